@@ -0,0 +1,91 @@
+use num_bigint::BigUint;
+use std::convert::TryFrom;
+use {ListWrapper, Permutator, ProductOrder};
+
+/// An arbitrary-precision rank, for inputs whose number of permutations exceeds a `u128`.
+pub type BigRank = BigUint;
+
+impl<ListWrap, ItemWrap> Permutator<ListWrap, ItemWrap>
+where
+    ListWrap: ListWrapper<ItemWrap>,
+{
+    /// Returns the total number of permutations possible, regardless of its magnitude.
+    pub fn big_max_permutations(&self) -> BigRank {
+        self.indexes
+            .lens
+            .as_ref()
+            .iter()
+            .fold(BigRank::from(1u32), |count, &len| count * len)
+    }
+
+    /// Computes the linear position of the permutation that the given `indexes` point to,
+    /// in the same manner as `rank_of()`, but without being limited to a `u128`.
+    ///
+    /// # Panics
+    /// This method will panic if the supplied indexes vector is not the correct length, or if
+    /// any index is out of bounds for its list.
+    pub fn big_rank_of(&self, indexes: &[usize]) -> BigRank {
+        assert!(
+            indexes.len() == self.indexes.lens.as_ref().len(),
+            "indexes have an invalid length"
+        );
+        assert!(
+            indexes
+                .iter()
+                .zip(self.indexes.lens.as_ref().iter())
+                .all(|(index, len)| index < len),
+            "indexes are out of bounds"
+        );
+        // A shuffled order requires the number of permutations to fit within a `u128`.
+        if let ProductOrder::Shuffled(_) = self.indexes.order {
+            return BigRank::from(self.rank_of(indexes));
+        }
+
+        let gray = self.indexes.order == ProductOrder::Gray;
+        let mut rank = BigRank::from(0u32);
+        let mut size = BigRank::from(1u32);
+        for &list in self.indexes.priority.as_ref().iter().rev() {
+            let index = indexes[list];
+            if gray && index % 2 == 1 {
+                rank = &size - 1u32 - rank;
+            }
+            rank += &size * index;
+            size *= self.indexes.lens.as_ref()[list];
+        }
+        rank
+    }
+
+    /// Returns the permutation found at the given linear position, in the same manner as
+    /// `nth_permutation()`, but without being limited to a `u128`.
+    pub fn big_nth_permutation(&self, rank: &BigRank) -> Option<ItemWrap> {
+        if *rank >= self.big_max_permutations() {
+            return None;
+        }
+        if let ProductOrder::Shuffled(_) = self.indexes.order {
+            return u128::try_from(rank)
+                .ok()
+                .and_then(|rank| self.nth_permutation(rank));
+        }
+
+        // The number of permutations of the less significant lists that follow each list.
+        let priority = self.indexes.priority.as_ref();
+        let mut sizes = vec![BigRank::from(1u32); priority.len()];
+        for position in (1..priority.len()).rev() {
+            sizes[position - 1] = &sizes[position] * self.indexes.lens.as_ref()[priority[position]];
+        }
+
+        let gray = self.indexes.order == ProductOrder::Gray;
+        let mut rank = rank.clone();
+        let mut indexes = vec![0; priority.len()];
+        for (&list, size) in priority.iter().zip(sizes) {
+            let digit = &rank / &size;
+            let digit = digit.iter_u64_digits().next().unwrap_or(0) as usize;
+            rank %= &size;
+            if gray && digit % 2 == 1 {
+                rank = size - 1u32 - rank;
+            }
+            indexes[list] = digit;
+        }
+        Some(ListWrap::next_item(&self.lists, &indexes))
+    }
+}
@@ -29,7 +29,10 @@ pub fn prepare(list_vector: Vec<Vec<String>>, benchmark: bool, no_delimiters: bo
 
         let mut permutator: PermutatorRepeated = Permutator::new(&list_array);
         if benchmark {
-            let _ = permutator.count();
+            // `count()` doesn't generate the permutations, so they are generated into a
+            // single buffer instead, as when they are printed.
+            let mut buffer = permutator.next().unwrap();
+            while permutator.next_with_buffer(&mut buffer).is_some() {}
         } else {
             if no_delimiters {
                 permutate_without_delims(&mut permutator);
@@ -50,7 +53,10 @@ pub fn prepare(list_vector: Vec<Vec<String>>, benchmark: bool, no_delimiters: bo
         // And then convert the `Permutator` with the &[&[&str]] as the input.
         let mut permutator: PermutatorStr = Permutator::new(&list_array);
         if benchmark {
-            let _ = permutator.count();
+            // `count()` doesn't generate the permutations, so they are generated into a
+            // single buffer instead, as when they are printed.
+            let mut buffer = permutator.next().unwrap();
+            while permutator.next_with_buffer(&mut buffer).is_some() {}
         } else {
             if no_delimiters {
                 permutate_without_delims(&mut permutator);
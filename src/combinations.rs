@@ -0,0 +1,342 @@
+use std::marker::PhantomData;
+
+use progress::Progress;
+use {ListWrapper, PermutateError, PermutatorWrapper, RepeatedLen};
+
+/// Generates the `n choose k` selections of `k` values taken from a list of `n` values, in
+/// lexicographic order of their indexes, where the order of the values within a selection is
+/// irrelevant. The list and `k` are supplied through the `RepeatedLen` wrapper, as with
+/// `KPermutations`.
+///
+/// Selections may be ranked and unranked through the combinatorial number system, so that
+/// any selection is accessible without generating those that precede it.
+///
+/// # Example
+///
+/// ```rust
+/// # use permutate::{Combinations, PermutatorWrapper as _, RepeatedLen};
+/// #
+/// let list = &["a", "b", "c", "d"][..];
+/// let combinations = Combinations::new(&RepeatedLen::new(list, 2));
+///
+/// let output = [
+///   &["a", "b"][..], &["a", "c"][..], &["a", "d"][..],
+///   &["b", "c"][..], &["b", "d"][..], &["c", "d"][..],
+/// ];
+/// #
+/// # assert_eq!(combinations.collect::<Vec<_>>(), output);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Combinations<'a, T: 'a> {
+    /// The list and the number of values to select from it.
+    lists: RepeatedLen<'a, T>,
+    /// The ascending indexes that point to the next selection from the front.
+    indexes: Vec<usize>,
+    /// The ascending indexes that point to the next selection from the back.
+    back_indexes: Vec<usize>,
+    /// The number of items generated from the front and back, out of the total.
+    progress: Progress,
+    _list_item_wrapper: PhantomData<T>,
+}
+
+/// Computes `n choose k`, or `None` if it does not fit within a `u128`.
+pub(crate) fn binomial(n: usize, k: usize) -> Option<u128> {
+    if k > n {
+        return Some(0);
+    }
+
+    let k = k.min(n - k);
+    (0..k).try_fold(1u128, |count, i| {
+        // `count * (n - i)` is always divisible by `i + 1`, so the common divisor is removed
+        // first in order to delay overflowing.
+        let (numerator, denominator) = ((n - i) as u128, (i + 1) as u128);
+        let divisor = gcd(count, denominator);
+        (count / divisor).checked_mul(numerator / (denominator / divisor))
+    })
+}
+
+fn gcd(mut a: u128, mut b: u128) -> u128 {
+    while b != 0 {
+        let r = a % b;
+        a = b;
+        b = r;
+    }
+    a
+}
+
+/// Moves the ascending `combination` of `0..n` towards the next lexicographic combination.
+pub(crate) fn next_combination(combination: &mut [usize], n: usize) {
+    let k = combination.len();
+    if let Some(i) = (0..k).rposition(|i| combination[i] < n - k + i) {
+        combination[i] += 1;
+        for j in i + 1..k {
+            combination[j] = combination[j - 1] + 1;
+        }
+    }
+}
+
+/// Moves the ascending `combination` of `0..n` towards the previous lexicographic combination.
+pub(crate) fn prev_combination(combination: &mut [usize], n: usize) {
+    let k = combination.len();
+    let lower = |combination: &[usize], i: usize| if i == 0 { 0 } else { combination[i - 1] + 1 };
+    if let Some(i) = (0..k).rposition(|i| combination[i] > lower(combination, i)) {
+        combination[i] -= 1;
+        for (j, value) in combination.iter_mut().enumerate().skip(i + 1) {
+            *value = n - k + j;
+        }
+    }
+}
+
+/// Converts the ascending `combination` of `0..n` into its lexicographic rank, through the
+/// combinatorial number system of the complemented indexes.
+pub(crate) fn rank(combination: &[usize], n: usize, count: u128) -> u128 {
+    let k = combination.len();
+    let complement = combination
+        .iter()
+        .enumerate()
+        .map(|(i, &index)| binomial(n - 1 - index, k - i).unwrap())
+        .sum::<u128>();
+    count - 1 - complement
+}
+
+/// Converts a lexicographic rank into the ascending `combination` of `0..n`.
+pub(crate) fn unrank(rank: u128, n: usize, count: u128, combination: &mut [usize]) {
+    let k = combination.len();
+    let mut complement = count - 1 - rank;
+    let mut candidate = n;
+    for (i, index) in combination.iter_mut().enumerate() {
+        // Find the largest value whose binomial coefficient fits within the remainder.
+        candidate -= 1;
+        let mut coefficient = binomial(candidate, k - i).unwrap();
+        while coefficient > complement {
+            candidate -= 1;
+            coefficient = binomial(candidate, k - i).unwrap();
+        }
+        complement -= coefficient;
+        *index = n - 1 - candidate;
+    }
+}
+
+impl<'a, T> Combinations<'a, T> {
+    fn len_and_k(&self) -> (usize, usize) {
+        (self.lists.list().len(), self.lists.len())
+    }
+
+    /// Computes the lexicographic position of the selection that the given ascending
+    /// `indexes` point to.
+    ///
+    /// # Panics
+    /// This method will panic if the indexes vector is not the correct length, if the indexes
+    /// are not strictly ascending and within bounds, or if the number of combinations does not
+    /// fit within a `u128`.
+    pub fn rank_of(&self, indexes: &[usize]) -> u128 {
+        let (n, k) = self.len_and_k();
+        if let Err(why) = validate(indexes, n, k) {
+            panic!("{}", why);
+        }
+        rank(indexes, n, self.progress.total())
+    }
+
+    /// Returns the selection found at the given lexicographic position, without modifying the
+    /// state of the generator. Returns `None` if `rank` is out of range.
+    ///
+    /// # Panics
+    /// This method will panic if the number of combinations does not fit within a `u128`.
+    pub fn nth_combination(&self, rank: u128) -> Option<Vec<T>>
+    where
+        T: Copy,
+    {
+        let (n, k) = self.len_and_k();
+        let count = self.progress.total();
+        if rank >= count {
+            return None;
+        }
+
+        let mut indexes = vec![0; k];
+        unrank(rank, n, count, &mut indexes);
+        Some(self.lists.next_item(&indexes))
+    }
+
+    /// Moves the front of the generator to the given lexicographic position, so that the
+    /// following call to `next()` returns the selection found at `rank`. Seeking to a rank at
+    /// or beyond the selections that remain at the back exhausts the generator.
+    ///
+    /// # Panics
+    /// This method will panic if the number of combinations does not fit within a `u128`.
+    pub fn seek(&mut self, rank: u128) {
+        let (n, _) = self.len_and_k();
+        let count = self.progress.total();
+        let end = count - self.progress.back_iter;
+        if rank >= end {
+            self.progress.curr_iter = end;
+            return;
+        }
+
+        unrank(rank, n, count, &mut self.indexes);
+        self.progress.curr_iter = rank;
+    }
+}
+
+/// Ensures that the `indexes` are a strictly ascending selection of `k` values out of `n`.
+fn validate(indexes: &[usize], n: usize, k: usize) -> Result<(), PermutateError> {
+    if indexes.len() != k {
+        return Err(PermutateError::InvalidLength(k, indexes.len()));
+    }
+    match (0..k).find(|&i| indexes[i] >= n || (i != 0 && indexes[i] <= indexes[i - 1])) {
+        Some(list) => Err(PermutateError::IndexOutOfBounds(list)),
+        None => Ok(()),
+    }
+}
+
+impl<'a, T> PermutatorWrapper<RepeatedLen<'a, T>, Vec<T>> for Combinations<'a, T>
+where
+    T: Copy,
+{
+    /// Initialize a new `Combinations` for the list and `k` of the `RepeatedLen` wrapper.
+    ///
+    /// # Panics
+    /// This method will panic if `k` is `0`.
+    fn new(lists: &RepeatedLen<'a, T>) -> Combinations<'a, T> {
+        let (n, k) = (lists.list().len(), lists.len());
+        assert!(k != 0, "{}", PermutateError::NoLists);
+
+        Combinations {
+            lists: *lists,
+            indexes: (0..k).collect(),
+            back_indexes: (n.saturating_sub(k)..n.max(k)).collect(),
+            progress: Progress::new(binomial(n, k), "combinations"),
+            _list_item_wrapper: PhantomData,
+        }
+    }
+
+    /// Sets the state of the front of the generator, where the `indexes` must be strictly
+    /// ascending.
+    fn set_index(&mut self, iter_no: u128, indexes: Vec<usize>) {
+        let (n, k) = self.len_and_k();
+        if let Err(why) = validate(&indexes, n, k) {
+            panic!("{}", why);
+        }
+        self.indexes = indexes;
+        self.progress.curr_iter = iter_no;
+    }
+
+    fn get_index(&self) -> (u128, Vec<usize>) {
+        (self.progress.curr_iter, self.indexes.clone())
+    }
+
+    fn max_permutations(&self) -> usize {
+        self.progress.total_usize()
+    }
+
+    fn checked_max_permutations(&self) -> Option<u128> {
+        self.progress.max_iters
+    }
+
+    fn reset(&mut self) {
+        *self = Combinations::new(&self.lists);
+    }
+
+    fn next_with_buffer<'b>(&mut self, buffer: &'b mut Vec<T>) -> Option<&'b mut Vec<T>> {
+        if self.progress.is_exhausted() {
+            return None;
+        }
+
+        self.progress.curr_iter += 1;
+        let (n, _) = self.len_and_k();
+        self.lists.next_with_buffer(&self.indexes, buffer);
+        next_combination(&mut self.indexes, n);
+        Some(buffer)
+    }
+
+    fn next_back_with_buffer<'b>(&mut self, buffer: &'b mut Vec<T>) -> Option<&'b mut Vec<T>> {
+        if self.progress.is_exhausted() {
+            return None;
+        }
+
+        self.progress.back_iter += 1;
+        let (n, _) = self.len_and_k();
+        self.lists.next_with_buffer(&self.back_indexes, buffer);
+        prev_combination(&mut self.back_indexes, n);
+        Some(buffer)
+    }
+}
+
+impl<'a, T> Iterator for Combinations<'a, T>
+where
+    T: Copy,
+{
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.progress.is_exhausted() {
+            return None;
+        }
+
+        self.progress.curr_iter += 1;
+        let (n, _) = self.len_and_k();
+        let output = self.lists.next_item(&self.indexes);
+        next_combination(&mut self.indexes, n);
+        Some(output)
+    }
+
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let n = n as u128;
+        let (len, _) = self.len_and_k();
+        match self.progress.max_iters {
+            Some(count) => {
+                let remaining = self.progress.remaining().unwrap_or(0);
+                if n >= remaining {
+                    self.progress.curr_iter += remaining;
+                    return None;
+                }
+
+                // Rather than stepping `n` times, jump straight to the requested position.
+                if n != 0 {
+                    let rank = rank(&self.indexes, len, count) + n;
+                    unrank(rank % count, len, count, &mut self.indexes);
+                    self.progress.curr_iter += n;
+                }
+            }
+            // The ranks do not fit within a `u128`, so the combinations are stepped through.
+            None => {
+                for _ in 0..n {
+                    next_combination(&mut self.indexes, len);
+                }
+                self.progress.curr_iter += n;
+            }
+        }
+
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.progress.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Combinations<'a, T>
+where
+    T: Copy,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.progress.is_exhausted() {
+            return None;
+        }
+
+        self.progress.back_iter += 1;
+        let (n, _) = self.len_and_k();
+        let output = self.lists.next_item(&self.back_indexes);
+        prev_combination(&mut self.back_indexes, n);
+        Some(output)
+    }
+}
+
+/// `len()` panics if the number of remaining combinations does not fit within a `usize`.
+impl<'a, T> ExactSizeIterator for Combinations<'a, T>
+where
+    T: Copy,
+{
+    fn len(&self) -> usize {
+        self.progress.len()
+    }
+}
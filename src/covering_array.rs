@@ -0,0 +1,327 @@
+use std::marker::PhantomData;
+
+use index_counters;
+use shuffle::mix;
+use ListWrapper;
+
+/// The number of candidate rows that are generated before the best of them is chosen.
+const CANDIDATES: usize = 16;
+
+/// Generates a small set of rows, taken from the same inputs as a `Permutator`, in which every
+/// combination of values from any `t` of the lists appears at least once. This is a `t`-wise
+/// covering array, which is pairwise by default, and allows a test matrix to exercise every
+/// interaction of `t` parameters without running the full product.
+///
+/// Rows are chosen greedily, by picking the candidate that covers the most combinations which
+/// were not covered yet, so the same lists, strength and seed always generate the same rows.
+///
+/// # Example
+///
+/// ```rust
+/// # use permutate::{CoveringArray, Permutator, PermutatorWrapper as _};
+/// #
+/// let lists = vec![&["a", "b", "c"][..], &["1", "2", "3"][..], &["x", "y", "z"][..]];
+/// let rows: Vec<Vec<&str>> = CoveringArray::new(&lists).collect();
+/// assert!(rows.len() < Permutator::new(&lists).count());
+///
+/// for (first, second) in vec![(0, 1), (0, 2), (1, 2)] {
+///     for a in lists[first] {
+///         for b in lists[second] {
+///             assert!(rows.iter().any(|row| row[first] == *a && row[second] == *b));
+///         }
+///     }
+/// }
+/// ```
+#[derive(Clone, Debug)]
+pub struct CoveringArray<ListWrap, ItemWrap>
+where
+    ListWrap: ListWrapper<ItemWrap>,
+{
+    /// The internal data that the rows are generated from.
+    lists: ListWrap,
+    /// The indexes of every row.
+    rows: Vec<Vec<usize>>,
+    /// The position of the next row from the front.
+    front: usize,
+    /// The position after the next row from the back.
+    back: usize,
+    _list_item_wrapper: PhantomData<ItemWrap>,
+}
+
+/// A deterministic source of pseudo-random numbers for breaking ties between candidates.
+struct Rng(u64);
+
+impl Rng {
+    fn next(&mut self) -> u64 {
+        self.0 = self.0.wrapping_add(1);
+        mix(self.0)
+    }
+
+    fn below(&mut self, bound: usize) -> usize {
+        (self.next() % bound as u64) as usize
+    }
+}
+
+/// Tracks which combinations of values have been covered for each combination of lists.
+struct Coverage<'a> {
+    lens: &'a [usize],
+    /// Each combination of `t` lists, in ascending order.
+    combos: Vec<Vec<usize>>,
+    /// Whether each combination of values has been covered, for each combination of lists.
+    covered: Vec<Vec<bool>>,
+    /// The combinations of lists that contain each list.
+    by_list: Vec<Vec<usize>>,
+    /// The number of combinations of values that have not been covered yet.
+    uncovered: usize,
+}
+
+impl<'a> Coverage<'a> {
+    fn new(lens: &'a [usize], strength: usize) -> Coverage<'a> {
+        let mut combos = Vec::new();
+        let mut combo: Vec<usize> = (0..strength).collect();
+        loop {
+            combos.push(combo.clone());
+            match (0..strength).rposition(|i| combo[i] < lens.len() - strength + i) {
+                Some(i) => {
+                    combo[i] += 1;
+                    for j in i + 1..strength {
+                        combo[j] = combo[j - 1] + 1;
+                    }
+                }
+                None => break,
+            }
+        }
+
+        let covered: Vec<Vec<bool>> = combos
+            .iter()
+            .map(|combo| vec![false; combo.iter().map(|&list| lens[list]).product()])
+            .collect();
+        let mut by_list = vec![Vec::new(); lens.len()];
+        for (position, combo) in combos.iter().enumerate() {
+            for &list in combo {
+                by_list[list].push(position);
+            }
+        }
+
+        Coverage {
+            lens,
+            uncovered: covered.iter().map(Vec::len).sum(),
+            combos,
+            covered,
+            by_list,
+        }
+    }
+
+    /// The position of the values of the `row` within a combination of lists, or `None` if
+    /// a value has not been chosen for one of those lists.
+    fn slot(&self, combo: usize, row: &[Option<usize>]) -> Option<usize> {
+        self.combos[combo]
+            .iter()
+            .try_fold(0, |slot, &list| Some(slot * self.lens[list] + row[list]?))
+    }
+
+    fn is_uncovered(&self, combo: usize, row: &[Option<usize>]) -> bool {
+        match self.slot(combo, row) {
+            Some(slot) => !self.covered[combo][slot],
+            None => false,
+        }
+    }
+
+    /// The number of uncovered combinations of values that the `row` would cover.
+    fn gain(&self, row: &[Option<usize>]) -> usize {
+        (0..self.combos.len())
+            .filter(|&combo| self.is_uncovered(combo, row))
+            .count()
+    }
+
+    fn cover(&mut self, row: &[Option<usize>]) {
+        for combo in 0..self.combos.len() {
+            if let Some(slot) = self.slot(combo, row) {
+                if !self.covered[combo][slot] {
+                    self.covered[combo][slot] = true;
+                    self.uncovered -= 1;
+                }
+            }
+        }
+    }
+
+    /// Generates a candidate row, which starts from a combination of values that has not been
+    /// covered yet, and then greedily chooses the value of each remaining list.
+    fn candidate(&self, rng: &mut Rng) -> Vec<Option<usize>> {
+        let mut row = vec![None; self.lens.len()];
+
+        let start = rng.below(self.combos.len());
+        let combo = (start..self.combos.len())
+            .chain(0..start)
+            .find(|&combo| self.covered[combo].contains(&false))
+            .expect("a combination remains to be covered");
+        let slots = self.covered[combo].len();
+        let start = rng.below(slots);
+        let mut slot = (start..slots)
+            .chain(0..start)
+            .find(|&slot| !self.covered[combo][slot])
+            .expect("a combination remains to be covered");
+        for &list in self.combos[combo].iter().rev() {
+            row[list] = Some(slot % self.lens[list]);
+            slot /= self.lens[list];
+        }
+
+        let mut remaining: Vec<usize> = (0..self.lens.len())
+            .filter(|&list| row[list].is_none())
+            .collect();
+        for i in (1..remaining.len()).rev() {
+            remaining.swap(i, rng.below(i + 1));
+        }
+
+        for list in remaining {
+            let len = self.lens[list];
+            let start = rng.below(len);
+            let mut best = (0, start);
+            for value in (start..len).chain(0..start) {
+                row[list] = Some(value);
+                let gain = self.by_list[list]
+                    .iter()
+                    .filter(|&&combo| self.is_uncovered(combo, &row))
+                    .count();
+                if gain > best.0 {
+                    best = (gain, value);
+                }
+            }
+            row[list] = Some(best.1);
+        }
+
+        row
+    }
+}
+
+impl<ListWrap, ItemWrap> CoveringArray<ListWrap, ItemWrap>
+where
+    ListWrap: ListWrapper<ItemWrap> + Clone,
+{
+    /// Generates a pairwise covering array, in which every pair of values from any two lists
+    /// appears in at least one row.
+    ///
+    /// # Panics
+    /// This method will panic if no lists were supplied, or if any of the lists are empty.
+    pub fn new(lists: &ListWrap) -> CoveringArray<ListWrap, ItemWrap> {
+        CoveringArray::with_strength(lists, 2, 0)
+    }
+
+    /// Generates a covering array in which every combination of values from any `strength`
+    /// of the lists appears in at least one row, where the `seed` selects between rows of
+    /// equal coverage. A strength that exceeds the number of lists covers the full product.
+    ///
+    /// # Panics
+    /// This method will panic if no lists were supplied, if any of the lists are empty, or if
+    /// `strength` is `0`.
+    pub fn with_strength(
+        lists: &ListWrap,
+        strength: usize,
+        seed: u64,
+    ) -> CoveringArray<ListWrap, ItemWrap> {
+        let lens = lists.lens();
+        if let Err(why) = index_counters::validate(&lens) {
+            panic!("{}", why);
+        }
+        assert!(strength != 0, "the strength must not be zero");
+
+        let mut coverage = Coverage::new(&lens, strength.min(lens.len()));
+        let mut rng = Rng(seed);
+        let mut rows = Vec::new();
+        while coverage.uncovered != 0 {
+            let mut best = coverage.candidate(&mut rng);
+            let mut best_gain = coverage.gain(&best);
+            for _ in 1..CANDIDATES {
+                let candidate = coverage.candidate(&mut rng);
+                let gain = coverage.gain(&candidate);
+                if gain > best_gain {
+                    best = candidate;
+                    best_gain = gain;
+                }
+            }
+
+            coverage.cover(&best);
+            rows.push(best.into_iter().map(|index| index.unwrap_or(0)).collect());
+        }
+
+        CoveringArray {
+            lists: lists.clone(),
+            back: rows.len(),
+            rows,
+            front: 0,
+            _list_item_wrapper: PhantomData,
+        }
+    }
+}
+
+impl<ListWrap, ItemWrap> CoveringArray<ListWrap, ItemWrap>
+where
+    ListWrap: ListWrapper<ItemWrap>,
+{
+    /// The indexes of every row of the covering array, including those already generated.
+    pub fn rows(&self) -> &[Vec<usize>] {
+        &self.rows
+    }
+
+    /// Resets the internal state to allow you to start generating rows again.
+    pub fn reset(&mut self) {
+        self.front = 0;
+        self.back = self.rows.len();
+    }
+
+    /// Provides similar functionality as the `Iterator` traits `next` method, but allows the
+    /// ability to re-use a prior allocation, in the same manner as
+    /// `Permutator::next_with_buffer()`.
+    ///
+    /// # Panics
+    /// This method will panic if the supplied buffer's length is invalid.
+    pub fn next_with_buffer<'b>(&mut self, buffer: &'b mut ItemWrap) -> Option<&'b mut ItemWrap> {
+        if self.front == self.back {
+            return None;
+        }
+
+        ListWrap::next_with_buffer(&self.lists, &self.rows[self.front], buffer);
+        self.front += 1;
+        Some(buffer)
+    }
+}
+
+impl<ListWrap, ItemWrap> Iterator for CoveringArray<ListWrap, ItemWrap>
+where
+    ListWrap: ListWrapper<ItemWrap>,
+{
+    type Item = ItemWrap;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.front += 1;
+        Some(ListWrap::next_item(&self.lists, &self.rows[self.front - 1]))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self.back - self.front;
+        (remaining, Some(remaining))
+    }
+}
+
+impl<ListWrap, ItemWrap> DoubleEndedIterator for CoveringArray<ListWrap, ItemWrap>
+where
+    ListWrap: ListWrapper<ItemWrap>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+
+        self.back -= 1;
+        Some(ListWrap::next_item(&self.lists, &self.rows[self.back]))
+    }
+}
+
+impl<ListWrap, ItemWrap> ExactSizeIterator for CoveringArray<ListWrap, ItemWrap> where
+    ListWrap: ListWrapper<ItemWrap>
+{
+}
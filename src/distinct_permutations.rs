@@ -0,0 +1,188 @@
+use combinations::binomial;
+use k_permutations::{next_permutation, prev_permutation};
+use progress::Progress;
+
+/// Generates each distinct arrangement of a list that may contain repeated values exactly
+/// once, for a total of `n! / (m1! * m2! * ...)` permutations, where `m1, m2, ...` are the
+/// multiplicities of the distinct values.
+///
+/// Values are only compared through `PartialEq`, and are mapped to the index of their first
+/// appearance within the list. Arrangements are then generated in lexicographic order of those
+/// indexes, so a list that is sorted beforehand yields its permutations in lexicographic
+/// order of the values.
+///
+/// # Example
+///
+/// ```rust
+/// # use permutate::DistinctPermutations;
+/// #
+/// let list = &["a", "b", "a"][..];
+/// let permutations = DistinctPermutations::new(list);
+/// assert_eq!(permutations.max_permutations(), 3);
+///
+/// let output = [&["a", "a", "b"][..], &["a", "b", "a"][..], &["b", "a", "a"][..]];
+/// #
+/// # assert_eq!(permutations.collect::<Vec<_>>(), output);
+/// ```
+#[derive(Clone, Debug)]
+pub struct DistinctPermutations<'a, T: 'a> {
+    /// One value of each distinct class, in order of first appearance within the list.
+    values: Vec<&'a T>,
+    /// The classes that point to the next permutation from the front.
+    indexes: Vec<usize>,
+    /// The classes that point to the next permutation from the back.
+    back_indexes: Vec<usize>,
+    /// The number of items generated from the front and back, out of the total.
+    progress: Progress,
+}
+
+/// Computes the number of distinct arrangements of values with the given multiplicities.
+fn multinomial(multiplicities: &[usize]) -> Option<u128> {
+    let mut total = 0;
+    multiplicities
+        .iter()
+        .try_fold(1u128, |count, &multiplicity| {
+            total += multiplicity;
+            count.checked_mul(binomial(total, multiplicity)?)
+        })
+}
+
+impl<'a, T: Copy + PartialEq> DistinctPermutations<'a, T> {
+    /// Initialize a new `DistinctPermutations` for the given list.
+    pub fn new(list: &'a [T]) -> DistinctPermutations<'a, T> {
+        let mut values: Vec<&'a T> = Vec::new();
+        let mut multiplicities = Vec::new();
+        let mut indexes = Vec::with_capacity(list.len());
+        for value in list {
+            let class = match values.iter().position(|&other| other == value) {
+                Some(class) => class,
+                None => {
+                    values.push(value);
+                    multiplicities.push(0);
+                    values.len() - 1
+                }
+            };
+            multiplicities[class] += 1;
+            indexes.push(class);
+        }
+
+        indexes.sort_unstable();
+        let mut back_indexes = indexes.clone();
+        back_indexes.reverse();
+
+        DistinctPermutations {
+            values,
+            indexes,
+            back_indexes,
+            progress: Progress::new(multinomial(&multiplicities), "permutations"),
+        }
+    }
+
+    /// Returns the total number of distinct permutations possible
+    ///
+    /// # Panics
+    /// This method will panic if the number of permutations does not fit within a `usize`.
+    pub fn max_permutations(&self) -> usize {
+        self.progress.total_usize()
+    }
+
+    /// Returns the total number of distinct permutations possible, or `None` if the count does
+    /// not fit within a `u128`.
+    pub fn checked_max_permutations(&self) -> Option<u128> {
+        self.progress.max_iters
+    }
+
+    /// Resets the internal state to allow you to start generating permutations again.
+    pub fn reset(&mut self) {
+        self.indexes.sort_unstable();
+        self.back_indexes.sort_unstable_by(|a, b| b.cmp(a));
+        self.progress.curr_iter = 0;
+        self.progress.back_iter = 0;
+    }
+
+    /// Provides similar functionality as the `Iterator` traits `next` method, but allows the
+    /// ability to re-use a prior allocation.
+    ///
+    /// # Panics
+    /// This method will panic if the buffer is shorter than the list.
+    pub fn next_with_buffer<'b>(&mut self, buffer: &'b mut Vec<T>) -> Option<&'b mut Vec<T>> {
+        if self.progress.is_exhausted() {
+            return None;
+        }
+
+        self.progress.curr_iter += 1;
+        self.fill(&self.indexes, buffer);
+        next_permutation(&mut self.indexes);
+        Some(buffer)
+    }
+
+    /// Provides similar functionality as the `DoubleEndedIterator` traits `next_back` method,
+    /// but allows the ability to re-use a prior allocation.
+    ///
+    /// # Panics
+    /// This method will panic if the buffer is shorter than the list.
+    pub fn next_back_with_buffer<'b>(&mut self, buffer: &'b mut Vec<T>) -> Option<&'b mut Vec<T>> {
+        if self.progress.is_exhausted() {
+            return None;
+        }
+
+        self.progress.back_iter += 1;
+        self.fill(&self.back_indexes, buffer);
+        prev_permutation(&mut self.back_indexes);
+        Some(buffer)
+    }
+
+    fn fill(&self, indexes: &[usize], buffer: &mut [T]) {
+        assert!(
+            buffer.len() >= indexes.len(),
+            "buffer is not large enough to contain the permutation"
+        );
+
+        for (slot, &class) in buffer.iter_mut().zip(indexes) {
+            *slot = *self.values[class];
+        }
+    }
+
+    fn collect_item(&self, indexes: &[usize]) -> Vec<T> {
+        indexes.iter().map(|&class| *self.values[class]).collect()
+    }
+}
+
+impl<'a, T: Copy + PartialEq> Iterator for DistinctPermutations<'a, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.progress.is_exhausted() {
+            return None;
+        }
+
+        self.progress.curr_iter += 1;
+        let output = self.collect_item(&self.indexes);
+        next_permutation(&mut self.indexes);
+        Some(output)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.progress.size_hint()
+    }
+}
+
+impl<'a, T: Copy + PartialEq> DoubleEndedIterator for DistinctPermutations<'a, T> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.progress.is_exhausted() {
+            return None;
+        }
+
+        self.progress.back_iter += 1;
+        let output = self.collect_item(&self.back_indexes);
+        prev_permutation(&mut self.back_indexes);
+        Some(output)
+    }
+}
+
+/// `len()` panics if the number of remaining permutations does not fit within a `usize`.
+impl<'a, T: Copy + PartialEq> ExactSizeIterator for DistinctPermutations<'a, T> {
+    fn len(&self) -> usize {
+        self.progress.len()
+    }
+}
@@ -0,0 +1,48 @@
+use std::error::Error;
+use std::fmt;
+
+/// The errors that may occur when creating a `Permutator`, or when setting its state.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum PermutateError {
+    /// No lists were supplied to permutate with.
+    NoLists,
+    /// The list found at the given position is empty.
+    EmptyList(usize),
+    /// The number of permutations does not fit within a `u128`.
+    CountOverflow,
+    /// The supplied indexes vector does not have the expected length. Contains the expected
+    /// and the supplied lengths.
+    InvalidLength(usize, usize),
+    /// The index supplied for the list found at the given position is out of bounds.
+    IndexOutOfBounds(usize),
+    /// The list wrapper reports a different number of lists than it has lengths for.
+    /// Contains the `wrapper_len()` and the number of `lens()`.
+    MismatchedLens(usize, usize),
+}
+
+impl fmt::Display for PermutateError {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match *self {
+            PermutateError::NoLists => write!(f, "no lists were supplied to permutate with"),
+            PermutateError::EmptyList(list) => write!(f, "list {} is empty", list),
+            PermutateError::CountOverflow => {
+                write!(f, "the number of permutations exceeds a u128")
+            }
+            PermutateError::InvalidLength(expected, found) => write!(
+                f,
+                "indexes have an invalid length: expected {}, found {}",
+                expected, found
+            ),
+            PermutateError::IndexOutOfBounds(list) => {
+                write!(f, "the index for list {} is out of bounds", list)
+            }
+            PermutateError::MismatchedLens(nlists, nlens) => write!(
+                f,
+                "the wrapper has {} lists, but the lengths of {}",
+                nlists, nlens
+            ),
+        }
+    }
+}
+
+impl Error for PermutateError {}
@@ -1,29 +1,146 @@
+use std::fmt::Debug;
+
+use shuffle::{shuffle, unshuffle};
+use PermutateError;
+
+/// The order in which a `Permutator` steps through the indexes of its lists.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ProductOrder {
+    /// The indexes are incremented like an odometer, where the last list varies the fastest,
+    /// so permutations are generated in lexicographic order of their indexes.
+    Lexicographic,
+    /// The indexes follow the reflected mixed-radix Gray code, in which exactly one list's
+    /// index changes, by one, between successive permutations.
+    Gray,
+    /// Each linear position is mapped through a bijection over the permutations, which is
+    /// shuffled by the given seed, so that every permutation is visited exactly once in an
+    /// order that is reproducible from the seed.
+    Shuffled(u64),
+}
+
+/// Selects which list's index varies the fastest as a `Permutator` steps through its
+/// permutations. The values of each permutation remain in the order of their lists.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum DimensionPriority {
+    /// The last list varies the fastest, and the first list the slowest.
+    LastFastest,
+    /// The first list varies the fastest, and the last list the slowest.
+    FirstFastest,
+    /// The positions of every list, ordered from the slowest varying list to the fastest
+    /// varying list, so that `Explicit(vec![0, 1, 2])` is the same as `LastFastest`.
+    Explicit(Vec<usize>),
+}
+
+impl DimensionPriority {
+    /// Resolves the priority into the positions of `nlists` lists, ordered from the slowest
+    /// varying list to the fastest varying list, or `None` if an explicit priority is not a
+    /// permutation of the positions.
+    pub(crate) fn resolve(&self, nlists: usize) -> Option<Vec<usize>> {
+        match *self {
+            DimensionPriority::LastFastest => Some((0..nlists).collect()),
+            DimensionPriority::FirstFastest => Some((0..nlists).rev().collect()),
+            DimensionPriority::Explicit(ref priority) => {
+                let mut sorted = priority.clone();
+                sorted.sort_unstable();
+                if sorted.into_iter().eq(0..nlists) {
+                    Some(priority.clone())
+                } else {
+                    None
+                }
+            }
+        }
+    }
+}
+
+/// Describes the single index that changed between two successive permutations generated in
+/// the `ProductOrder::Gray` order.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct IndexChange {
+    /// The position of the list whose index changed.
+    pub list: usize,
+    /// The index into that list before the change.
+    pub from: usize,
+    /// The index into that list after the change.
+    pub to: usize,
+}
+
+/// The storage of one value for each list, such as its index or its length. This is a
+/// `Vec<usize>`, unless the number of lists is known at compile time, in which case it may be
+/// a `[usize; N]` array that is kept within the permutator rather than on the heap.
+pub trait IndexStorage: AsRef<[usize]> + AsMut<[usize]> + Clone + Debug + Send + Sync {
+    /// Copies the given values, one for each list, into a new storage.
+    ///
+    /// # Panics
+    /// This method may panic if the number of values differs from the number of lists.
+    fn from_slice(values: &[usize]) -> Self;
+}
+
+impl IndexStorage for Vec<usize> {
+    fn from_slice(values: &[usize]) -> Vec<usize> {
+        values.to_vec()
+    }
+}
+
+impl<const N: usize> IndexStorage for [usize; N] {
+    fn from_slice(values: &[usize]) -> [usize; N] {
+        let mut array = [0; N];
+        array.copy_from_slice(values);
+        array
+    }
+}
+
 #[derive(Clone, Debug)]
 /// Tracks the state of the indexes of each list.
-pub struct IndexCounters {
+pub struct IndexCounters<I> {
     /// The current state of the indexes
-    pub indexes: Vec<usize>,
+    pub indexes: I,
     /// The lengths of the wrapped lists
-    pub lens: Vec<usize>,
+    pub lens: I,
+    /// The state of the indexes when iterating from the back
+    pub back_indexes: I,
     /// The current iteration position
-    pub curr_iter: usize,
-    /// The maximum number of iterations to perform
-    pub max_iters: usize,
+    pub curr_iter: u128,
+    /// The number of iterations performed from the back
+    pub back_iter: u128,
+    /// The maximum number of iterations to perform, or `None` if it exceeds a `u128`
+    pub max_iters: Option<u128>,
+    /// The order in which the indexes are stepped through
+    pub order: ProductOrder,
+    /// The positions of the lists, ordered from the slowest varying to the fastest varying
+    pub priority: I,
 }
 
-impl IndexCounters {
-    /// Increments & resets index indexes according to their maximum values.
+impl<I> IndexCounters<I>
+where
+    I: IndexStorage,
+{
+    /// Increments & resets index indexes according to their maximum values, wrapping around
+    /// to the first permutation once the most significant list overflows.
+    #[inline]
     pub fn increment(&mut self, mut nlists: usize) {
+        if self.order != ProductOrder::Lexicographic {
+            let (order, max_iters) = (self.order, self.max_iters);
+            let (lens, priority) = (self.lens.as_ref(), self.priority.as_ref());
+            step_unordered(
+                order,
+                max_iters,
+                lens,
+                priority,
+                self.indexes.as_mut(),
+                true,
+            );
+            return;
+        }
+
         loop {
             let mut increment = false;
             {
-                let current = unsafe { self.indexes.get_unchecked_mut(nlists) };
-                let max = unsafe { self.lens.get_unchecked(nlists) };
+                let list = unsafe { *self.priority.as_ref().get_unchecked(nlists) };
+                let current = unsafe { self.indexes.as_mut().get_unchecked_mut(list) };
+                let max = unsafe { self.lens.as_ref().get_unchecked(list) };
                 if *current + 1 >= *max {
-                    if nlists != 0 {
-                        *current = 0;
-                        increment = true;
-                    }
+                    *current = 0;
+                    increment = nlists != 0;
                 } else {
                     *current += 1;
                 }
@@ -37,9 +154,468 @@ impl IndexCounters {
         }
     }
 
+    /// Decrements & resets the back indexes according to their maximum values, wrapping
+    /// around to the last permutation once the most significant list underflows.
+    #[inline]
+    pub fn decrement(&mut self, mut nlists: usize) {
+        if self.order != ProductOrder::Lexicographic {
+            let (order, max_iters) = (self.order, self.max_iters);
+            let (lens, priority) = (self.lens.as_ref(), self.priority.as_ref());
+            step_unordered(
+                order,
+                max_iters,
+                lens,
+                priority,
+                self.back_indexes.as_mut(),
+                false,
+            );
+            return;
+        }
+
+        loop {
+            let mut decrement = false;
+            {
+                let list = unsafe { *self.priority.as_ref().get_unchecked(nlists) };
+                let current = unsafe { self.back_indexes.as_mut().get_unchecked_mut(list) };
+                let max = unsafe { self.lens.as_ref().get_unchecked(list) };
+                if *current == 0 {
+                    *current = *max - 1;
+                    decrement = nlists != 0;
+                } else {
+                    *current -= 1;
+                }
+            }
+
+            if decrement {
+                nlists -= 1;
+            } else {
+                break;
+            }
+        }
+    }
+
     pub fn reset(&mut self) {
-        for value in self.indexes.iter_mut() {
+        for value in self.indexes.as_mut().iter_mut() {
             *value = 0;
         }
+        let (lens, priority) = (self.lens.as_ref(), self.priority.as_ref());
+        match self.order {
+            ProductOrder::Lexicographic => {
+                for (value, len) in self.back_indexes.as_mut().iter_mut().zip(lens.iter()) {
+                    *value = len.saturating_sub(1);
+                }
+            }
+            ProductOrder::Gray => gray_last(lens, priority, self.back_indexes.as_mut()),
+            ProductOrder::Shuffled(_) => {
+                let max = self
+                    .max_iters
+                    .expect("the number of permutations exceeds a u128");
+                self.set_position(0);
+                self.set_back_position(max - 1);
+            }
+        }
+        self.back_iter = 0;
+    }
+
+    /// Steps the indexes to the next permutation of the reflected Gray code, returning the
+    /// change that was made, or `None` if the indexes wrapped around to the first permutation.
+    pub fn gray_increment(&mut self) -> Option<IndexChange> {
+        let indexes = self.indexes.as_mut();
+        match gray_movable(indexes, self.lens.as_ref(), self.priority.as_ref(), true) {
+            Some((list, up)) => {
+                let from = indexes[list];
+                step(&mut indexes[list], up);
+                Some(IndexChange {
+                    list,
+                    from,
+                    to: indexes[list],
+                })
+            }
+            None => {
+                indexes.iter_mut().for_each(|index| *index = 0);
+                None
+            }
+        }
+    }
+
+    /// The number of permutations that remain between the front and back iterations, or
+    /// `None` if the permutator is unbounded.
+    pub fn remaining(&self) -> Option<u128> {
+        self.max_iters
+            .map(|max| max.saturating_sub(self.curr_iter + self.back_iter))
+    }
+
+    /// Whether the front and back iterations have met, and thus no permutations remain.
+    pub fn is_exhausted(&self) -> bool {
+        match self.max_iters {
+            Some(max) => self.curr_iter + self.back_iter >= max,
+            None => false,
+        }
+    }
+
+    /// Points the indexes to the given linear position, wrapping around if the
+    /// position exceeds the number of possible permutations.
+    pub fn set_position(&mut self, rank: u128) {
+        let (order, max_iters) = (self.order, self.max_iters);
+        let (lens, priority) = (self.lens.as_ref(), self.priority.as_ref());
+        unrank_in_order(
+            order,
+            max_iters,
+            lens,
+            priority,
+            rank,
+            self.indexes.as_mut(),
+        );
+    }
+
+    /// Points the back indexes to the given linear position, in the same manner as
+    /// `set_position()`.
+    fn set_back_position(&mut self, rank: u128) {
+        let (order, max_iters) = (self.order, self.max_iters);
+        let (lens, priority) = (self.lens.as_ref(), self.priority.as_ref());
+        unrank_in_order(
+            order,
+            max_iters,
+            lens,
+            priority,
+            rank,
+            self.back_indexes.as_mut(),
+        );
+    }
+
+    /// Restricts the front and back of the indexes to the `len` linear positions from
+    /// `start`, wrapping around past the last permutation, where `len` must not exceed the
+    /// `max` number of possible permutations.
+    pub fn set_range(&mut self, start: u128, len: u128, max: u128) {
+        self.set_position(start);
+        if len != 0 {
+            self.set_back_position(offset(Some(max), start, len - 1));
+        }
+        self.curr_iter = start.min(max - len);
+        self.back_iter = max - len - self.curr_iter;
+    }
+
+    /// The linear position of the front indexes, which may differ from `curr_iter` once the
+    /// indexes have been set directly.
+    pub fn front_rank(&self) -> u128 {
+        self.rank_of(self.indexes.as_ref())
+            .expect("the rank exceeds a u128")
+    }
+
+    /// Converts a linear position into the indexes found there in the current order.
+    pub fn unrank_into(&self, rank: u128, indexes: &mut [usize]) {
+        let (order, max_iters) = (self.order, self.max_iters);
+        let (lens, priority) = (self.lens.as_ref(), self.priority.as_ref());
+        unrank_in_order(order, max_iters, lens, priority, rank, indexes)
+    }
+
+    /// Converts indexes into their linear position in the current order, or `None` if the
+    /// position does not fit within a `u128`.
+    pub fn rank_of(&self, indexes: &[usize]) -> Option<u128> {
+        let (lens, priority) = (self.lens.as_ref(), self.priority.as_ref());
+        rank_in_order(self.order, self.max_iters, lens, priority, indexes)
+    }
+
+    /// Advances the indexes by the mixed-radix `delta`, which holds an index for each list,
+    /// carrying across each list, and wrapping around once the first list overflows. Unlike
+    /// `add()`, this needs no division, so a fixed stride may be precomputed once as a delta.
+    pub fn add_delta(&mut self, delta: &[usize]) {
+        let (lens, indexes) = (self.lens.as_ref(), self.indexes.as_mut());
+        let mut carry = 0;
+        for &list in self.priority.as_ref().iter().rev() {
+            let (index, len) = (&mut indexes[list], lens[list]);
+            let sum = *index + delta[list] + carry;
+            if sum >= len {
+                *index = sum - len;
+                carry = 1;
+            } else {
+                *index = sum;
+                carry = 0;
+            }
+        }
+    }
+
+    /// Moves the front past the permutations that share the indexes of the `depth` most
+    /// significant lists with the front, without passing the back. Unless `inclusive` is set,
+    /// the front stays in place when it starts a new block, as it then shares none of those
+    /// indexes with the permutation before it.
+    pub fn skip_block(&mut self, depth: usize, inclusive: bool) {
+        let lens = self.lens.as_ref();
+        let size = self.priority.as_ref()[depth..]
+            .iter()
+            .try_fold(1u128, |size, &list| size.checked_mul(lens[list] as u128))
+            .expect("the number of permutations exceeds a u128");
+        let rank = self
+            .rank_of(self.indexes.as_ref())
+            .expect("the rank exceeds a u128");
+        let offset = rank % size;
+        if offset == 0 && !inclusive {
+            return;
+        }
+
+        let skipped = match self.remaining() {
+            Some(remaining) => (size - offset).min(remaining),
+            None => size - offset,
+        };
+        self.curr_iter += skipped;
+        self.add(skipped);
+    }
+
+    /// Advances the indexes by `n` positions, carrying across each list, and wrapping
+    /// around once the first list overflows.
+    pub fn add(&mut self, mut n: u128) {
+        if self.order != ProductOrder::Lexicographic {
+            let (order, max_iters) = (self.order, self.max_iters);
+            let (lens, priority) = (self.lens.as_ref(), self.priority.as_ref());
+            add_unordered(order, max_iters, lens, priority, self.indexes.as_mut(), n);
+            return;
+        }
+
+        let (lens, indexes) = (self.lens.as_ref(), self.indexes.as_mut());
+        for &list in self.priority.as_ref().iter().rev() {
+            if n == 0 {
+                break;
+            }
+            let (index, len) = (&mut indexes[list], lens[list] as u128);
+            let sum = *index as u128 + n % len;
+            *index = (sum % len) as usize;
+            n = n / len + sum / len;
+        }
+    }
+}
+
+/// Steps the `indexes` forwards or backwards by one position in the Gray code or shuffled
+/// orders. This is kept out of line so that the lexicographic `increment()` and
+/// `decrement()`, which are inlined into every step of a permutator, stay small.
+#[inline(never)]
+fn step_unordered(
+    order: ProductOrder,
+    max_iters: Option<u128>,
+    lens: &[usize],
+    priority: &[usize],
+    indexes: &mut [usize],
+    forwards: bool,
+) {
+    match order {
+        ProductOrder::Lexicographic => unreachable!(),
+        ProductOrder::Gray => match gray_movable(indexes, lens, priority, forwards) {
+            Some((list, up)) => step(&mut indexes[list], up),
+            None if forwards => indexes.iter_mut().for_each(|index| *index = 0),
+            None => gray_last(lens, priority, indexes),
+        },
+        ProductOrder::Shuffled(_) => {
+            let n = if forwards {
+                1
+            } else {
+                max_iters.map_or(1, |max| max - 1)
+            };
+            add_unordered(order, max_iters, lens, priority, indexes, n);
+        }
+    }
+}
+
+/// Advances the `indexes` by `n` positions in the Gray code or shuffled orders. Only the
+/// odometer has carries to follow, so these go through the linear position instead.
+#[inline(never)]
+fn add_unordered(
+    order: ProductOrder,
+    max_iters: Option<u128>,
+    lens: &[usize],
+    priority: &[usize],
+    indexes: &mut [usize],
+    n: u128,
+) {
+    let rank =
+        rank_in_order(order, max_iters, lens, priority, indexes).expect("the rank exceeds a u128");
+    let rank = offset(max_iters, rank, n);
+    unrank_in_order(order, max_iters, lens, priority, rank, indexes);
+}
+
+/// Adds `n` to the linear position `rank`, wrapping around if the sum exceeds the
+/// number of possible permutations.
+pub fn offset(max_iters: Option<u128>, rank: u128, n: u128) -> u128 {
+    match max_iters {
+        Some(max) => {
+            let n = n % max;
+            if rank >= max - n {
+                rank - (max - n)
+            } else {
+                rank + n
+            }
+        }
+        None => rank.checked_add(n).expect("the rank exceeds a u128"),
+    }
+}
+
+/// Converts a linear position into the indexes found there in the given `order`.
+fn unrank_in_order(
+    order: ProductOrder,
+    max_iters: Option<u128>,
+    lens: &[usize],
+    priority: &[usize],
+    rank: u128,
+    indexes: &mut [usize],
+) {
+    match order {
+        ProductOrder::Lexicographic => unrank(lens, priority, rank, indexes),
+        ProductOrder::Gray => gray_unrank(lens, priority, rank, indexes),
+        ProductOrder::Shuffled(seed) => {
+            let max = max_iters.expect("the number of permutations exceeds a u128");
+            let rank = shuffle(seed, max, rank % max);
+            unrank(lens, priority, rank, indexes)
+        }
+    }
+}
+
+/// Converts indexes into their linear position in the given `order`, or `None` if the
+/// position does not fit within a `u128`.
+fn rank_in_order(
+    order: ProductOrder,
+    max_iters: Option<u128>,
+    lens: &[usize],
+    priority: &[usize],
+    indexes: &[usize],
+) -> Option<u128> {
+    match order {
+        ProductOrder::Lexicographic => rank(lens, priority, indexes),
+        ProductOrder::Gray => gray_rank(lens, priority, indexes),
+        ProductOrder::Shuffled(seed) => {
+            let max = max_iters?;
+            let rank = rank(lens, priority, indexes)?;
+            Some(unshuffle(seed, max, rank))
+        }
+    }
+}
+
+/// Converts a linear position into the mixed-radix `indexes` described by `lens`, where the
+/// `priority` orders the lists from the most significant to the least significant one.
+pub fn unrank(lens: &[usize], priority: &[usize], mut rank: u128, indexes: &mut [usize]) {
+    for &list in priority.iter().rev() {
+        let len = lens[list] as u128;
+        indexes[list] = (rank % len) as usize;
+        rank /= len;
+    }
+}
+
+/// Converts the mixed-radix `indexes` described by `lens` and `priority` into their linear
+/// position, or `None` if the position does not fit within a `u128`.
+pub fn rank(lens: &[usize], priority: &[usize], indexes: &[usize]) -> Option<u128> {
+    priority.iter().try_fold(0u128, |rank, &list| {
+        rank.checked_mul(lens[list] as u128)?
+            .checked_add(indexes[list] as u128)
+    })
+}
+
+/// Converts a linear position into the `indexes` found there within the reflected mixed-radix
+/// Gray code described by `lens` and `priority`, wrapping around if the position exceeds the
+/// number of possible permutations.
+pub fn gray_unrank(lens: &[usize], priority: &[usize], mut rank: u128, indexes: &mut [usize]) {
+    // The number of permutations of the less significant lists that follow each list.
+    let mut sizes = vec![None; priority.len()];
+    let mut size = Some(1u128);
+    for (slot, &list) in sizes.iter_mut().zip(priority.iter()).rev() {
+        *slot = size;
+        size = size.and_then(|size| size.checked_mul(lens[list] as u128));
+    }
+
+    for (&list, size) in priority.iter().zip(sizes) {
+        indexes[list] = match size {
+            Some(size) => {
+                let digit = (rank / size % lens[list] as u128) as usize;
+                rank %= size;
+                // The lists that follow an odd index are traversed in reverse.
+                if digit % 2 == 1 {
+                    rank = size - 1 - rank;
+                }
+                digit
+            }
+            // The position is too small to reach this list.
+            None => 0,
+        };
+    }
+}
+
+/// Converts the `indexes` of the reflected mixed-radix Gray code described by `lens` and
+/// `priority` into their linear position, or `None` if the position does not fit within a
+/// `u128`.
+pub fn gray_rank(lens: &[usize], priority: &[usize], indexes: &[usize]) -> Option<u128> {
+    let mut rank = 0u128;
+    let mut size = Some(1u128);
+    for &list in priority.iter().rev() {
+        let index = indexes[list];
+        if index % 2 == 1 {
+            rank = size? - 1 - rank;
+        }
+        if index != 0 {
+            rank = (index as u128).checked_mul(size?)?.checked_add(rank)?;
+        }
+        size = size.and_then(|size| size.checked_mul(lens[list] as u128));
+    }
+    Some(rank)
+}
+
+/// Finds the least significant list whose index may step within the reflected Gray code,
+/// either forwards or backwards, along with whether that step increases the index. An index
+/// increases when the sum of the more significant indexes is even, and decreases otherwise.
+fn gray_movable(
+    indexes: &[usize],
+    lens: &[usize],
+    priority: &[usize],
+    forwards: bool,
+) -> Option<(usize, bool)> {
+    let mut movable = None;
+    let mut odd = false;
+    for &list in priority {
+        let up = odd != forwards;
+        let index = indexes[list];
+        if (up && index + 1 < lens[list]) || (!up && index != 0) {
+            movable = Some((list, up));
+        }
+        odd ^= index % 2 == 1;
+    }
+    movable
+}
+
+/// Points the `indexes` to the last permutation of the reflected Gray code.
+fn gray_last(lens: &[usize], priority: &[usize], indexes: &mut [usize]) {
+    let mut odd = false;
+    for &list in priority {
+        indexes[list] = if odd { 0 } else { lens[list].saturating_sub(1) };
+        odd ^= indexes[list] % 2 == 1;
+    }
+}
+
+fn step(index: &mut usize, up: bool) {
+    if up {
+        *index += 1;
+    } else {
+        *index -= 1;
+    }
+}
+
+/// Computes the number of permutations for lists of the given `lens`, or `None` if
+/// the count does not fit within a `u128`.
+pub fn count(lens: &[usize]) -> Option<u128> {
+    lens.iter()
+        .try_fold(1u128, |count, &len| count.checked_mul(len as u128))
+}
+
+/// Ensures that there are lists to permutate with, and that none of them are empty.
+pub fn validate(lens: &[usize]) -> Result<(), PermutateError> {
+    if lens.is_empty() {
+        return Err(PermutateError::NoLists);
+    }
+    match lens.iter().position(|&len| len == 0) {
+        Some(list) => Err(PermutateError::EmptyList(list)),
+        None => Ok(()),
+    }
+}
+
+/// Ensures that a wrapper of `nlists` lists has a length for each of them, as the counters
+/// are indexed by list without being bounds checked, before validating the `lens`.
+pub fn validate_wrapper(nlists: usize, lens: &[usize]) -> Result<(), PermutateError> {
+    if nlists != lens.len() {
+        return Err(PermutateError::MismatchedLens(nlists, lens.len()));
     }
+    validate(lens)
 }
@@ -0,0 +1,378 @@
+use std::marker::PhantomData;
+
+use combinations::{next_combination, prev_combination};
+use progress::Progress;
+use {ListWrapper, PermutateError, PermutatorWrapper, RepeatedLen};
+
+/// The order in which `KPermutations` arranges the values of the list.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PermutationOrder {
+    /// Permutations are generated in lexicographic order of their indexes.
+    Lexicographic,
+    /// Each selection of values, taken in lexicographic order, is arranged with Heap's
+    /// algorithm, so that successive permutations of a selection differ by a single swap.
+    Heap,
+}
+
+/// Generates the `n!/(n-k)!` orderings of `k` distinct values taken from a list of `n` values,
+/// which are the permutations without repetition. The list and `k` are supplied through the
+/// `RepeatedLen` wrapper, so that the same input that yields words with repetition may yield
+/// the words without repetition instead.
+///
+/// # Example
+///
+/// ```rust
+/// # use permutate::{KPermutations, PermutatorWrapper as _, RepeatedLen};
+/// #
+/// let list = &["a", "b", "c"][..];
+/// let permutator = KPermutations::new(&RepeatedLen::new(list, 2));
+///
+/// let output = [
+///   &["a", "b"][..], &["a", "c"][..],
+///   &["b", "a"][..], &["b", "c"][..],
+///   &["c", "a"][..], &["c", "b"][..],
+/// ];
+/// #
+/// # assert_eq!(permutator.collect::<Vec<_>>(), output);
+/// ```
+#[derive(Clone, Debug)]
+pub struct KPermutations<'a, T: 'a> {
+    /// The list and the number of values to select from it.
+    lists: RepeatedLen<'a, T>,
+    /// The order in which the permutations are generated.
+    order: PermutationOrder,
+    /// The state that points to the next permutation from the front.
+    front: KState,
+    /// The state that points to the next permutation from the back.
+    back: KState,
+    /// The number of items generated from the front and back, out of the total.
+    progress: Progress,
+    _list_item_wrapper: PhantomData<T>,
+}
+
+/// The indexes of a single k-permutation, along with the bookkeeping that is required to
+/// step towards its neighbours.
+#[derive(Clone, Debug)]
+struct KState {
+    /// The `k` selected indexes in their current arrangement.
+    indexes: Vec<usize>,
+    /// All `n` indexes, where the first `k` are selected and the rest are sorted
+    /// (`Lexicographic` only).
+    permutation: Vec<usize>,
+    /// The selected indexes in ascending order (`Heap` only).
+    combination: Vec<usize>,
+    /// The mixed-radix counters of Heap's algorithm, where counter `i` ranges `0..=i`.
+    counters: Vec<usize>,
+}
+
+impl KState {
+    fn first(n: usize, k: usize, order: PermutationOrder) -> KState {
+        match order {
+            PermutationOrder::Lexicographic => KState {
+                indexes: (0..k).collect(),
+                permutation: (0..n).collect(),
+                combination: Vec::new(),
+                counters: Vec::new(),
+            },
+            PermutationOrder::Heap => KState {
+                indexes: (0..k).collect(),
+                permutation: Vec::new(),
+                combination: (0..k).collect(),
+                counters: vec![0; k],
+            },
+        }
+    }
+
+    fn last(n: usize, k: usize, order: PermutationOrder) -> KState {
+        match order {
+            PermutationOrder::Lexicographic => KState {
+                indexes: (n - k..n).rev().collect(),
+                permutation: (n - k..n).rev().chain(0..n - k).collect(),
+                combination: Vec::new(),
+                counters: Vec::new(),
+            },
+            PermutationOrder::Heap => {
+                let combination: Vec<usize> = (n - k..n).collect();
+                KState {
+                    indexes: heap_last(k).iter().map(|&i| combination[i]).collect(),
+                    permutation: Vec::new(),
+                    combination,
+                    counters: (0..k).collect(),
+                }
+            }
+        }
+    }
+
+    fn advance(&mut self, n: usize, k: usize, order: PermutationOrder) {
+        match order {
+            PermutationOrder::Lexicographic => {
+                // Reversing the unselected indexes skips every arrangement of them.
+                self.permutation[k..].reverse();
+                next_permutation(&mut self.permutation);
+                self.indexes.copy_from_slice(&self.permutation[..k]);
+            }
+            PermutationOrder::Heap => match (1..k).find(|&i| self.counters[i] < i) {
+                Some(i) => {
+                    let j = if i % 2 == 0 { 0 } else { self.counters[i] };
+                    self.indexes.swap(j, i);
+                    self.counters[i] += 1;
+                    self.counters[1..i].iter_mut().for_each(|c| *c = 0);
+                }
+                None => {
+                    next_combination(&mut self.combination, n);
+                    self.indexes.copy_from_slice(&self.combination);
+                    self.counters.iter_mut().for_each(|c| *c = 0);
+                }
+            },
+        }
+    }
+
+    fn retreat(&mut self, n: usize, k: usize, order: PermutationOrder) {
+        match order {
+            PermutationOrder::Lexicographic => {
+                prev_permutation(&mut self.permutation);
+                self.permutation[k..].reverse();
+                self.indexes.copy_from_slice(&self.permutation[..k]);
+            }
+            PermutationOrder::Heap => match (1..k).find(|&i| self.counters[i] > 0) {
+                Some(i) => {
+                    (1..i).for_each(|j| self.counters[j] = j);
+                    self.counters[i] -= 1;
+                    // Each swap is its own inverse, so repeating it undoes the step.
+                    let j = if i % 2 == 0 { 0 } else { self.counters[i] };
+                    self.indexes.swap(j, i);
+                }
+                None => {
+                    prev_combination(&mut self.combination, n);
+                    for (index, &i) in self.indexes.iter_mut().zip(heap_last(k).iter()) {
+                        *index = self.combination[i];
+                    }
+                    (0..k).for_each(|j| self.counters[j] = j);
+                }
+            },
+        }
+    }
+}
+
+/// The arrangement of `0..k` after Heap's algorithm has generated every permutation.
+fn heap_last(k: usize) -> Vec<usize> {
+    match k {
+        1 => vec![0],
+        2 => vec![1, 0],
+        _ if k % 2 == 1 => Some(k - 1)
+            .into_iter()
+            .chain(1..k - 1)
+            .chain(Some(0))
+            .collect(),
+        _ => vec![k - 3, k - 2]
+            .into_iter()
+            .chain(1..k - 3)
+            .chain(vec![k - 1, 0])
+            .collect(),
+    }
+}
+
+/// Rearranges the indexes into the next lexicographic permutation, wrapping around.
+pub(crate) fn next_permutation(indexes: &mut [usize]) {
+    match indexes.windows(2).rposition(|pair| pair[0] < pair[1]) {
+        Some(i) => {
+            let j = indexes.iter().rposition(|&v| v > indexes[i]).unwrap();
+            indexes.swap(i, j);
+            indexes[i + 1..].reverse();
+        }
+        None => indexes.reverse(),
+    }
+}
+
+/// Rearranges the indexes into the previous lexicographic permutation, wrapping around.
+pub(crate) fn prev_permutation(indexes: &mut [usize]) {
+    match indexes.windows(2).rposition(|pair| pair[0] > pair[1]) {
+        Some(i) => {
+            let j = indexes.iter().rposition(|&v| v < indexes[i]).unwrap();
+            indexes.swap(i, j);
+            indexes[i + 1..].reverse();
+        }
+        None => indexes.reverse(),
+    }
+}
+
+impl<'a, T> KPermutations<'a, T> {
+    /// Initialize a new `KPermutations` that generates its permutations in the given `order`.
+    ///
+    /// # Panics
+    /// This method will panic if `k` is `0`.
+    pub fn with_order(lists: &RepeatedLen<'a, T>, order: PermutationOrder) -> KPermutations<'a, T> {
+        let (n, k) = (lists.list().len(), lists.len());
+        assert!(k != 0, "{}", PermutateError::NoLists);
+
+        // When `k` exceeds `n`, there are no permutations, and the states are never read.
+        let max_iters = if k > n {
+            Some(0)
+        } else {
+            (n - k + 1..=n).try_fold(1u128, |count, v| count.checked_mul(v as u128))
+        };
+        let (front, back) = if k > n {
+            (KState::first(k, k, order), KState::last(k, k, order))
+        } else {
+            (KState::first(n, k, order), KState::last(n, k, order))
+        };
+
+        KPermutations {
+            lists: *lists,
+            order,
+            front,
+            back,
+            progress: Progress::new(max_iters, "permutations"),
+            _list_item_wrapper: PhantomData,
+        }
+    }
+
+    /// The order in which the permutations are generated.
+    pub fn order(&self) -> PermutationOrder {
+        self.order
+    }
+}
+
+impl<'a, T> PermutatorWrapper<RepeatedLen<'a, T>, Vec<T>> for KPermutations<'a, T>
+where
+    T: Copy,
+{
+    /// Initialize a new `KPermutations` that generates its permutations in lexicographic order.
+    fn new(lists: &RepeatedLen<'a, T>) -> KPermutations<'a, T> {
+        KPermutations::with_order(lists, PermutationOrder::Lexicographic)
+    }
+
+    /// Sets the state of the front of the permutator. The `indexes` are the selected indexes,
+    /// followed by the counters of Heap's algorithm when the order is `Heap`.
+    fn set_index(&mut self, iter_no: u128, indexes: Vec<usize>) {
+        let (n, k) = (self.lists.list().len(), self.lists.len());
+        let expected = match self.order {
+            PermutationOrder::Lexicographic => k,
+            PermutationOrder::Heap => 2 * k,
+        };
+        if indexes.len() != expected {
+            panic!("{}", PermutateError::InvalidLength(expected, indexes.len()));
+        }
+
+        let mut selected = vec![false; n];
+        for (list, &index) in indexes[..k].iter().enumerate() {
+            if index >= n || selected[index] {
+                panic!("{}", PermutateError::IndexOutOfBounds(list));
+            }
+            selected[index] = true;
+        }
+
+        match self.order {
+            PermutationOrder::Lexicographic => {
+                let mut permutation = indexes.clone();
+                permutation.extend((0..n).filter(|&index| !selected[index]));
+                self.front.indexes = indexes;
+                self.front.permutation = permutation;
+            }
+            PermutationOrder::Heap => {
+                let (arrangement, counters) = indexes.split_at(k);
+                if let Some(list) = (0..k).find(|&i| counters[i] > i) {
+                    panic!("{}", PermutateError::IndexOutOfBounds(k + list));
+                }
+                self.front.indexes = arrangement.to_vec();
+                self.front.combination = (0..n).filter(|&index| selected[index]).collect();
+                self.front.counters = counters.to_vec();
+            }
+        }
+        self.progress.curr_iter = iter_no;
+    }
+
+    fn get_index(&self) -> (u128, Vec<usize>) {
+        let mut indexes = self.front.indexes.clone();
+        if self.order == PermutationOrder::Heap {
+            indexes.extend_from_slice(&self.front.counters);
+        }
+        (self.progress.curr_iter, indexes)
+    }
+
+    fn max_permutations(&self) -> usize {
+        self.progress.total_usize()
+    }
+
+    fn checked_max_permutations(&self) -> Option<u128> {
+        self.progress.max_iters
+    }
+
+    fn reset(&mut self) {
+        *self = KPermutations::with_order(&self.lists, self.order);
+    }
+
+    fn next_with_buffer<'b>(&mut self, buffer: &'b mut Vec<T>) -> Option<&'b mut Vec<T>> {
+        if self.progress.is_exhausted() {
+            return None;
+        }
+
+        self.progress.curr_iter += 1;
+        let (n, k) = (self.lists.list().len(), self.lists.len());
+        self.lists.next_with_buffer(&self.front.indexes, buffer);
+        self.front.advance(n, k, self.order);
+        Some(buffer)
+    }
+
+    fn next_back_with_buffer<'b>(&mut self, buffer: &'b mut Vec<T>) -> Option<&'b mut Vec<T>> {
+        if self.progress.is_exhausted() {
+            return None;
+        }
+
+        self.progress.back_iter += 1;
+        let (n, k) = (self.lists.list().len(), self.lists.len());
+        self.lists.next_with_buffer(&self.back.indexes, buffer);
+        self.back.retreat(n, k, self.order);
+        Some(buffer)
+    }
+}
+
+impl<'a, T> Iterator for KPermutations<'a, T>
+where
+    T: Copy,
+{
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.progress.is_exhausted() {
+            return None;
+        }
+
+        self.progress.curr_iter += 1;
+        let (n, k) = (self.lists.list().len(), self.lists.len());
+        let output = self.lists.next_item(&self.front.indexes);
+        self.front.advance(n, k, self.order);
+        Some(output)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.progress.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for KPermutations<'a, T>
+where
+    T: Copy,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.progress.is_exhausted() {
+            return None;
+        }
+
+        self.progress.back_iter += 1;
+        let (n, k) = (self.lists.list().len(), self.lists.len());
+        let output = self.lists.next_item(&self.back.indexes);
+        self.back.retreat(n, k, self.order);
+        Some(output)
+    }
+}
+
+/// `len()` panics if the number of remaining permutations does not fit within a `usize`.
+impl<'a, T> ExactSizeIterator for KPermutations<'a, T>
+where
+    T: Copy,
+{
+    fn len(&self) -> usize {
+        self.progress.len()
+    }
+}
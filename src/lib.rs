@@ -1,21 +1,63 @@
-#![feature(external_doc)]
-#![doc(include = "../README.md")]
+#![doc = include_str!("../README.md")]
 #![deny(missing_docs)]
 
+#[cfg(feature = "big-rank")]
+extern crate num_bigint;
+#[cfg(feature = "rayon")]
+extern crate rayon;
+
 /// Utilities to be used by the binary and benchmarking.
 #[cfg(feature = "bin-utils")]
 pub mod bin;
 
 use std::marker::PhantomData;
+use std::ops::Range;
 
+#[cfg(feature = "big-rank")]
+mod big_rank;
+mod combinations;
+mod covering_array;
+mod distinct_permutations;
+mod error;
 mod index_counters;
+mod k_permutations;
 mod list_wrapper;
+mod multisets;
+#[cfg(feature = "rayon")]
+mod parallel;
+mod power_set;
+mod progress;
+mod pruned;
+mod repeated_range;
+mod shuffle;
+mod strided;
 
+#[cfg(feature = "big-rank")]
+pub use big_rank::BigRank;
+pub use combinations::Combinations;
+pub use covering_array::CoveringArray;
+pub use distinct_permutations::DistinctPermutations;
+pub use error::PermutateError;
 use index_counters::IndexCounters;
-pub use list_wrapper::{ListWrapper, Repeated};
+pub use index_counters::{DimensionPriority, IndexChange, IndexStorage, ProductOrder};
+pub use k_permutations::{KPermutations, PermutationOrder};
+pub use list_wrapper::{
+    ByClone, ByRef, Fixed, ListLookup, ListWrapper, Optional, Repeated, RepeatedLen,
+};
+pub use multisets::Multisets;
+#[cfg(feature = "rayon")]
+pub use parallel::ParallelPermutator;
+pub use power_set::{PowerSet, PowerSetOrder};
+pub use pruned::Pruned;
+pub use repeated_range::RepeatedRange;
+pub use strided::Strided;
 
 /// The `PermutatorWrapper` contains the methods (creation, etc) which any Permutator should
 /// implement.
+///
+/// The number of permutations may exceed a `usize`, or even a `u128`, in which case the size
+/// hint of a permutator has no upper bound, and its `ExactSizeIterator::len()` panics, as does
+/// `max_permutations()`. `checked_max_permutations()` reports the count without panicking.
 pub trait PermutatorWrapper<ListWrap, ItemWrap>
 where
     ListWrap: ListWrapper<ItemWrap>,
@@ -24,7 +66,13 @@ where
     /// Initialize a new `Permutator` with the vec/tuple of input slices to permutate with.
     /// The input may be provided as either multiple lists via a vec/tuple of slices, or a single
     /// list as an slice within an array.
-    fn new(lists: &ListWrap) -> Permutator<ListWrap, ItemWrap>;
+    ///
+    /// # Panics
+    /// This method will panic if no lists were supplied, if any of the lists are empty, or if
+    /// the wrapper's `lens()` do not have an entry for each of its lists.
+    fn new(lists: &ListWrap) -> Self
+    where
+        Self: Sized;
 
     /// Sets the internal index counter's values to a specific state, which you will
     /// typically obtain when using the `get_index()` method. The `iter_no` parameter
@@ -34,15 +82,24 @@ where
     /// for all possible values before the supplied state.
     ///
     /// # Panics
-    /// This method will panic if the supplied indexes vector is not the correct length
-    fn set_index(&mut self, iter_no: usize, indexes: Vec<usize>);
+    /// This method will panic if the supplied indexes vector is not the correct length, or if
+    /// any of its indexes are out of bounds.
+    fn set_index(&mut self, iter_no: u128, indexes: Vec<usize>);
 
     /// Obtains the current iteration number and the index counter's indexes.
-    fn get_index(&self) -> (usize, Vec<usize>);
+    fn get_index(&self) -> (u128, Vec<usize>);
 
     /// Returns the total number of permutations possible
+    ///
+    /// # Panics
+    /// This method will panic if the number of permutations does not fit within a `usize`.
+    /// Use `checked_max_permutations()` when the input may describe a huge keyspace.
     fn max_permutations(&self) -> usize;
 
+    /// Returns the total number of permutations possible, or `None` if the count does not
+    /// fit within a `u128`.
+    fn checked_max_permutations(&self) -> Option<u128>;
+
     /// Resets the internal state of the `Permutator` to allow you to start permutating again.
     fn reset(&mut self);
 
@@ -56,6 +113,17 @@ where
     /// # Panics
     /// This method will panic if the supplied buffer's length is invalid.
     fn next_with_buffer<'b>(&mut self, buffer: &'b mut ItemWrap) -> Option<&'b mut ItemWrap>;
+
+    /// Provides similar functionality as the `DoubleEndedIterator` traits `next_back` method,
+    /// but allows the ability to supply your own buffer, in the same manner as
+    /// `next_with_buffer`.
+    ///
+    /// Permutations are generated from the last towards the first, and iteration stops once
+    /// the front and back of the permutator meet.
+    ///
+    /// # Panics
+    /// This method will panic if the supplied buffer's length is invalid.
+    fn next_back_with_buffer<'b>(&mut self, buffer: &'b mut ItemWrap) -> Option<&'b mut ItemWrap>;
 }
 
 /// The `Permutator` contains the state of the iterator as well as the owned values and/or
@@ -67,9 +135,12 @@ where
     ListWrap: ListWrapper<ItemWrap>,
 {
     /// The indexes is used to point to the next permutation sequence.
-    indexes: IndexCounters,
+    indexes: IndexCounters<ListWrap::Indexes>,
     /// The internal data that the permutator is permutating against.
     lists: ListWrap,
+    /// The permutation that was most recently lent out by `next_ref()`, which is reused as
+    /// the buffer for the next one.
+    buffer: Option<ItemWrap>,
     /// Phantom type, so that when implementing the `Iterator` for this structure,
     /// `ItemWrap` type may be used as `Iterator::Item`.
     _list_item_wrapper: PhantomData<ItemWrap>,
@@ -82,36 +153,53 @@ where
     fn new(lists: &ListWrap) -> Permutator<ListWrap, ItemWrap> {
         let nlists = lists.wrapper_len();
         let nvalues = lists.lens();
-        let max_iters = nvalues.iter().product();
+        if let Err(why) = index_counters::validate_wrapper(nlists, &nvalues) {
+            panic!("{}", why);
+        }
+        let max_iters = index_counters::count(&nvalues);
+        let back_indexes: Vec<usize> = nvalues.iter().map(|len| len.saturating_sub(1)).collect();
+        let priority: Vec<usize> = (0..nlists).collect();
 
         Permutator {
             indexes: IndexCounters {
-                indexes: vec![0; nlists],
-                lens: nvalues,
+                indexes: IndexStorage::from_slice(&vec![0; nlists]),
+                lens: IndexStorage::from_slice(&nvalues),
+                back_indexes: IndexStorage::from_slice(&back_indexes),
                 curr_iter: 0,
-                max_iters: max_iters,
+                back_iter: 0,
+                max_iters,
+                order: ProductOrder::Lexicographic,
+                priority: IndexStorage::from_slice(&priority),
             },
             lists: lists.clone(),
+            buffer: None,
             _list_item_wrapper: PhantomData,
         }
     }
 
-    fn set_index(&mut self, iter_no: usize, indexes: Vec<usize>) {
-        debug_assert!(
-            indexes.len() == self.indexes.lens.len(),
-            "indexes have an invalid length"
-        );
-        self.indexes.indexes = indexes;
-        self.indexes.curr_iter = iter_no;
+    fn set_index(&mut self, iter_no: u128, indexes: Vec<usize>) {
+        if let Err(why) = self.try_set_index(iter_no, indexes) {
+            panic!("{}", why);
+        }
     }
 
     /// Obtains the current iteration number and the index counter's indexes.
-    fn get_index(&self) -> (usize, Vec<usize>) {
-        (self.indexes.curr_iter, self.indexes.indexes.clone())
+    fn get_index(&self) -> (u128, Vec<usize>) {
+        (
+            self.indexes.curr_iter,
+            self.indexes.indexes.as_ref().to_vec(),
+        )
     }
 
     /// Returns the total number of permutations possible
     fn max_permutations(&self) -> usize {
+        match self.indexes.max_iters {
+            Some(max) if max <= usize::MAX as u128 => max as usize,
+            _ => panic!("the number of permutations exceeds a usize"),
+        }
+    }
+
+    fn checked_max_permutations(&self) -> Option<u128> {
         self.indexes.max_iters
     }
 
@@ -122,16 +210,461 @@ where
     }
 
     fn next_with_buffer<'b>(&mut self, buffer: &'b mut ItemWrap) -> Option<&'b mut ItemWrap> {
-        if self.indexes.max_iters != 0 && self.indexes.curr_iter == self.indexes.max_iters {
+        if self.indexes.is_exhausted() {
             return None;
         }
 
         self.indexes.curr_iter += 1;
         let self_lists: &mut _ = &mut self.lists;
-        ListWrap::next_with_buffer(self_lists, &self.indexes.indexes, buffer);
+        ListWrap::next_with_buffer(self_lists, self.indexes.indexes.as_ref(), buffer);
         self.indexes.increment(self_lists.wrapper_len() - 1);
         Some(buffer)
     }
+
+    fn next_back_with_buffer<'b>(&mut self, buffer: &'b mut ItemWrap) -> Option<&'b mut ItemWrap> {
+        if self.indexes.is_exhausted() {
+            return None;
+        }
+
+        self.indexes.back_iter += 1;
+        let self_lists: &mut _ = &mut self.lists;
+        ListWrap::next_with_buffer(self_lists, self.indexes.back_indexes.as_ref(), buffer);
+        self.indexes.decrement(self_lists.wrapper_len() - 1);
+        Some(buffer)
+    }
+}
+
+impl<ListWrap, ItemWrap> Permutator<ListWrap, ItemWrap>
+where
+    ListWrap: ListWrapper<ItemWrap> + Clone,
+{
+    /// Initialize a new `Permutator` in the same manner as `new()`, but returns an error
+    /// rather than panicking if no lists were supplied, if any of the lists are empty, if the
+    /// wrapper's `lens()` do not cover each of its lists, or if the number of permutations
+    /// does not fit within a `u128`.
+    pub fn try_new(lists: &ListWrap) -> Result<Permutator<ListWrap, ItemWrap>, PermutateError> {
+        let nvalues = lists.lens();
+        index_counters::validate_wrapper(lists.wrapper_len(), &nvalues)?;
+        if index_counters::count(&nvalues).is_none() {
+            return Err(PermutateError::CountOverflow);
+        }
+
+        Ok(Permutator::new(lists))
+    }
+
+    /// Returns an independent permutator that generates the permutations whose linear
+    /// positions are within `range`, in the same order as this permutator. The front and back
+    /// of the returned permutator start at either end of the range, but `reset()` returns it
+    /// to the full set of permutations.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use permutate::{Permutator, PermutatorWrapper as _};
+    /// #
+    /// let lists = vec![&[1, 2][..], &[3, 4, 5][..]];
+    /// let permutator = Permutator::new(&lists);
+    /// let output = permutator.range(2..4).collect::<Vec<_>>();
+    /// assert_eq!(output, [[1, 5], [2, 3]]);
+    /// ```
+    ///
+    /// # Panics
+    /// This method will panic if the range is decreasing or exceeds the number of
+    /// permutations, or if the number of permutations does not fit within a `u128`.
+    pub fn range(&self, range: Range<u128>) -> Permutator<ListWrap, ItemWrap> {
+        let max = self
+            .indexes
+            .max_iters
+            .expect("the number of permutations exceeds a u128");
+        assert!(
+            range.start <= range.end && range.end <= max,
+            "the range is out of bounds"
+        );
+
+        self.span(range.start, range.end - range.start, max)
+    }
+
+    /// Returns an independent permutator that generates the `len` permutations from the
+    /// linear position `start`, wrapping around past the last of the `max` permutations.
+    fn span(&self, start: u128, len: u128, max: u128) -> Permutator<ListWrap, ItemWrap> {
+        let mut permutator = Permutator {
+            indexes: self.indexes.clone(),
+            lists: self.lists.clone(),
+            buffer: None,
+            _list_item_wrapper: PhantomData,
+        };
+        permutator.indexes.set_range(start, len, max);
+        permutator
+    }
+
+    /// Splits the permutations that remain between the front and back of this permutator
+    /// into two independent permutators, where the first generates the permutations before
+    /// the linear position `rank`, and the second generates those from `rank` onwards. A rank
+    /// outside of the remaining permutations leaves one of the two permutators empty.
+    ///
+    /// The remaining permutations start from the front indexes, so after `set_index()` they
+    /// may wrap around past the last permutation, in the same manner as `next()`.
+    ///
+    /// # Panics
+    /// This method will panic if the number of permutations does not fit within a `u128`.
+    pub fn split_at(
+        &self,
+        rank: u128,
+    ) -> (
+        Permutator<ListWrap, ItemWrap>,
+        Permutator<ListWrap, ItemWrap>,
+    ) {
+        let max = self
+            .indexes
+            .max_iters
+            .expect("the number of permutations exceeds a u128");
+        let remaining = max.saturating_sub(self.indexes.curr_iter + self.indexes.back_iter);
+        let start = self.indexes.front_rank();
+        // Ranks before the front are only reached when the remaining permutations wrap around.
+        let len = if rank >= start {
+            rank - start
+        } else if remaining > max - start {
+            max - start + rank
+        } else {
+            0
+        };
+        self.split_after(len)
+    }
+
+    /// Splits the permutations that remain between the front and back of this permutator
+    /// after the first `len` of them.
+    fn split_after(
+        &self,
+        len: u128,
+    ) -> (
+        Permutator<ListWrap, ItemWrap>,
+        Permutator<ListWrap, ItemWrap>,
+    ) {
+        let max = self
+            .indexes
+            .max_iters
+            .expect("the number of permutations exceeds a u128");
+        let remaining = max.saturating_sub(self.indexes.curr_iter + self.indexes.back_iter);
+        let start = self.indexes.front_rank();
+        let len = len.min(remaining);
+        let rank = index_counters::offset(Some(max), start, len);
+        (
+            self.span(start, len, max),
+            self.span(rank, remaining - len, max),
+        )
+    }
+
+    /// Initialize a new `Permutator` in the same manner as `new()`, but steps through the
+    /// permutations in the given `order`.
+    ///
+    /// # Panics
+    /// This method will panic for the same reasons as `new()`, or if the order is
+    /// `ProductOrder::Shuffled(_)` and the number of permutations does not fit within a `u128`.
+    pub fn with_order(lists: &ListWrap, order: ProductOrder) -> Permutator<ListWrap, ItemWrap> {
+        let mut permutator = Permutator::new(lists);
+        permutator.indexes.order = order;
+        permutator.indexes.reset();
+        permutator
+    }
+}
+
+impl<ListWrap, ItemWrap> Permutator<ListWrap, ItemWrap>
+where
+    ListWrap: ListWrapper<ItemWrap>,
+{
+    /// Sets the internal index counter's values to a specific state, in the same manner as
+    /// `set_index()`, but returns an error rather than panicking if the supplied indexes
+    /// vector is not the correct length, or if any of its indexes are out of bounds.
+    pub fn try_set_index(
+        &mut self,
+        iter_no: u128,
+        indexes: Vec<usize>,
+    ) -> Result<(), PermutateError> {
+        if indexes.len() != self.indexes.lens.as_ref().len() {
+            return Err(PermutateError::InvalidLength(
+                self.indexes.lens.as_ref().len(),
+                indexes.len(),
+            ));
+        }
+        if let Some(list) = indexes
+            .iter()
+            .zip(self.indexes.lens.as_ref().iter())
+            .position(|(index, len)| index >= len)
+        {
+            return Err(PermutateError::IndexOutOfBounds(list));
+        }
+
+        self.indexes.indexes = IndexStorage::from_slice(&indexes);
+        self.indexes.curr_iter = iter_no;
+        Ok(())
+    }
+
+    /// Moves the permutator to the given linear position, so that the following call to
+    /// `next()` returns the permutation found at `rank`. The indexes are computed directly
+    /// from the rank, so this costs one division per list regardless of the distance.
+    ///
+    /// Only the front of the permutator is moved. Seeking to a rank at or beyond the
+    /// permutations that remain at the back exhausts the permutator.
+    pub fn seek(&mut self, rank: u128) {
+        if let Some(max) = self.indexes.max_iters {
+            let end = max - self.indexes.back_iter;
+            if rank >= end {
+                self.indexes.curr_iter = end;
+                return;
+            }
+        }
+
+        self.indexes.set_position(rank);
+        self.indexes.curr_iter = rank;
+    }
+
+    /// Returns the permutation found at the given linear position, without modifying the
+    /// state of the permutator. Returns `None` if `rank` is out of range.
+    pub fn nth_permutation(&self, rank: u128) -> Option<ItemWrap> {
+        match self.indexes.max_iters {
+            Some(max) if rank >= max => return None,
+            _ => (),
+        }
+
+        let mut indexes = vec![0; self.indexes.lens.as_ref().len()];
+        self.indexes.unrank_into(rank, &mut indexes);
+        Some(ListWrap::next_item(&self.lists, &indexes))
+    }
+
+    /// Computes the linear position of the permutation that the given `indexes` point to,
+    /// which is the inverse of `nth_permutation()`. The returned rank may be supplied to
+    /// `seek()` in order to resume from that permutation.
+    ///
+    /// # Panics
+    /// This method will panic if the supplied indexes vector is not the correct length, if
+    /// any index is out of bounds for its list, or if the rank does not fit within a `u128`.
+    pub fn rank_of(&self, indexes: &[usize]) -> u128 {
+        assert!(
+            indexes.len() == self.indexes.lens.as_ref().len(),
+            "indexes have an invalid length"
+        );
+        assert!(
+            indexes
+                .iter()
+                .zip(self.indexes.lens.as_ref().iter())
+                .all(|(index, len)| index < len),
+            "indexes are out of bounds"
+        );
+        self.indexes
+            .rank_of(indexes)
+            .expect("the rank exceeds a u128")
+    }
+
+    /// Computes the linear position of the given permutation by looking up each of its values
+    /// within the list that it originates from. When a list contains duplicate values, the
+    /// first occurrence is used. Returns `None` if any value cannot be found.
+    ///
+    /// # Panics
+    /// This method will panic if the rank does not fit within a `u128`.
+    pub fn rank_of_item(&self, item: &ItemWrap) -> Option<u128>
+    where
+        ListWrap: ListLookup<ItemWrap>,
+    {
+        self.lists.indexes_of(item).map(|indexes| {
+            self.indexes
+                .rank_of(&indexes)
+                .expect("the rank exceeds a u128")
+        })
+    }
+
+    /// The order in which the permutator steps through the permutations.
+    pub fn order(&self) -> ProductOrder {
+        self.indexes.order
+    }
+
+    /// Selects which list's index varies the fastest, and resets the permutator so that it
+    /// starts permutating again in the new order. The values within each permutation remain
+    /// in the order of their lists, so an expensive-to-change list may be made the slowest
+    /// without reordering the output.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use permutate::{DimensionPriority, Permutator, PermutatorWrapper as _};
+    /// #
+    /// let lists = vec![&["a", "b"][..], &["1", "2"][..]];
+    /// let mut permutator = Permutator::new(&lists);
+    /// permutator.set_priority(DimensionPriority::FirstFastest);
+    ///
+    /// let output = [&["a", "1"][..], &["b", "1"][..], &["a", "2"][..], &["b", "2"][..]];
+    /// #
+    /// # assert_eq!(permutator.collect::<Vec<_>>(), output);
+    /// ```
+    ///
+    /// # Panics
+    /// This method will panic if an explicit priority is not a permutation of the positions
+    /// of the lists.
+    pub fn set_priority(&mut self, priority: DimensionPriority) {
+        let priority = priority
+            .resolve(self.indexes.lens.as_ref().len())
+            .expect("the priority is not a permutation of the list positions");
+        self.indexes.priority = IndexStorage::from_slice(&priority);
+        self.indexes.reset();
+        self.indexes.curr_iter = 0;
+    }
+
+    /// The positions of the lists, ordered from the slowest varying list to the fastest
+    /// varying list.
+    pub fn priority(&self) -> &[usize] {
+        self.indexes.priority.as_ref()
+    }
+
+    /// Consumes the permutation that the indexes currently point to, without generating it,
+    /// and reports the single index that changes in order to point to the following
+    /// permutation. Returns `None` once no permutation follows the consumed one.
+    ///
+    /// Together with `get_index()`, this allows a consumer to apply each permutation as a
+    /// change to the previous one, rather than generating every permutation in full.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use permutate::{IndexChange, Permutator, PermutatorWrapper as _, ProductOrder};
+    /// #
+    /// let lists = vec![&[1, 2][..], &[3, 4, 5][..]];
+    /// let mut permutator = Permutator::with_order(&lists, ProductOrder::Gray);
+    /// assert_eq!(permutator.get_index().1, [0, 0]);
+    ///
+    /// let change = permutator.next_change();
+    /// assert_eq!(change, Some(IndexChange { list: 1, from: 0, to: 1 }));
+    /// assert_eq!(permutator.get_index().1, [0, 1]);
+    /// ```
+    ///
+    /// # Panics
+    /// This method will panic if the permutator does not step in the `ProductOrder::Gray`
+    /// order, where more than one index may change between permutations.
+    pub fn next_change(&mut self) -> Option<IndexChange> {
+        assert!(
+            self.indexes.order == ProductOrder::Gray,
+            "next_change() requires the Gray code order"
+        );
+        if self.indexes.is_exhausted() {
+            return None;
+        }
+
+        self.indexes.curr_iter += 1;
+        let change = self.indexes.gray_increment();
+        if self.indexes.is_exhausted() {
+            return None;
+        }
+        change
+    }
+
+    /// Skips every remaining permutation that shares the indexes of the first `depth` lists
+    /// with the permutation that was most recently generated from the front, so that a whole
+    /// subtree of permutations may be rejected once a prefix of them is known to be invalid.
+    /// With a `DimensionPriority`, the first `depth` lists are the slowest varying ones.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use permutate::{Permutator, PermutatorWrapper as _};
+    /// #
+    /// let lists = vec![&["linux", "windows"][..], &["x86", "arm"][..], &["gcc", "clang"][..]];
+    /// let mut permutator = Permutator::new(&lists);
+    /// let mut output = Vec::new();
+    /// while let Some(values) = permutator.next() {
+    ///     if values[..2] == ["windows", "arm"] {
+    ///         permutator.skip_subtree(2);
+    ///         continue;
+    ///     }
+    ///     output.push(values);
+    /// }
+    /// assert_eq!(output.len(), 6);
+    /// assert_eq!(output[5], ["windows", "x86", "clang"]);
+    /// ```
+    ///
+    /// # Panics
+    /// This method will panic if `depth` exceeds the number of lists, if the permutator steps
+    /// in a shuffled order, where the permutations of a subtree are not adjacent, or if the
+    /// number of permutations does not fit within a `u128`.
+    pub fn skip_subtree(&mut self, depth: usize) {
+        assert!(
+            depth <= self.indexes.lens.as_ref().len(),
+            "the depth exceeds the number of lists"
+        );
+        if let ProductOrder::Shuffled(_) = self.indexes.order {
+            panic!("subtrees may not be skipped in a shuffled order");
+        }
+        self.indexes.skip_block(depth, false);
+    }
+
+    /// Generates the next permutation into a buffer that is kept within the permutator, and
+    /// lends out a reference to it, which remains valid until the permutator is next used.
+    /// Only the first permutation allocates, and every subsequent one reuses its buffer.
+    pub fn next_ref(&mut self) -> Option<&ItemWrap> {
+        if self.indexes.is_exhausted() {
+            return None;
+        }
+
+        self.indexes.curr_iter += 1;
+        match self.buffer {
+            Some(ref mut buffer) => {
+                ListWrap::next_with_buffer(&self.lists, self.indexes.indexes.as_ref(), buffer)
+            }
+            None => {
+                self.buffer = Some(ListWrap::next_item(
+                    &self.lists,
+                    self.indexes.indexes.as_ref(),
+                ))
+            }
+        }
+        self.indexes.increment(self.lists.wrapper_len() - 1);
+        self.buffer.as_ref()
+    }
+
+    /// Calls `f` with a reference to each of the remaining permutations, which are all
+    /// generated into the same internal buffer, as with `next_ref()`.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use permutate::{Permutator, PermutatorWrapper as _};
+    /// #
+    /// let lists = vec![&["a", "b"][..], &["1", "2", "3"][..]];
+    /// let mut permutator = Permutator::new(&lists);
+    /// let mut joined = Vec::new();
+    /// permutator.for_each_ref(|values| joined.push(values.concat()));
+    /// assert_eq!(joined, ["a1", "a2", "a3", "b1", "b2", "b3"]);
+    /// ```
+    pub fn for_each_ref<F>(&mut self, mut f: F)
+    where
+        F: FnMut(&ItemWrap),
+    {
+        while let Some(item) = self.next_ref() {
+            f(item);
+        }
+    }
+}
+
+impl<ListWrap, T> Permutator<ListWrap, Vec<T>>
+where
+    ListWrap: ListWrapper<Vec<T>>,
+{
+    /// Generates the next permutation in the same manner as `next_ref()`, but lends it out
+    /// as a slice of values.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use permutate::{Permutator, PermutatorWrapper as _};
+    /// #
+    /// let lists = vec![&[1, 2][..], &[3, 4][..]];
+    /// let mut permutator = Permutator::new(&lists);
+    /// let mut sums = Vec::new();
+    /// while let Some(values) = permutator.next_slice() {
+    ///     sums.push(values.iter().sum::<i32>());
+    /// }
+    /// assert_eq!(sums, [4, 5, 5, 6]);
+    /// ```
+    pub fn next_slice(&mut self) -> Option<&[T]> {
+        self.next_ref().map(|values| &values[..])
+    }
 }
 
 impl<ListWrap, ItemWrap> Iterator for Permutator<ListWrap, ItemWrap>
@@ -140,35 +673,55 @@ where
 {
     type Item = ItemWrap;
 
-    fn nth(&mut self, mut n: usize) -> Option<Self::Item> {
-        loop {
-            if self.indexes.max_iters != 0 && self.indexes.curr_iter == self.indexes.max_iters {
+    fn nth(&mut self, n: usize) -> Option<Self::Item> {
+        let n = n as u128;
+        if let Some(remaining) = self.indexes.remaining() {
+            if n >= remaining {
+                self.indexes.curr_iter += remaining;
                 return None;
             }
+        }
 
-            self.indexes.curr_iter += 1;
+        // Rather than incrementing `n` times, jump straight to the requested position.
+        if n != 0 {
+            self.indexes.add(n);
+            self.indexes.curr_iter += n;
+        }
 
-            if let _should_skip @ true = n != 0 {
-                self.indexes.increment(&self.lists.wrapper_len() - 1);
-                n -= 1;
-            } else {
-                let output = ListWrap::next_item(&self.lists, &self.indexes.indexes);
-                self.indexes.increment(&self.lists.wrapper_len() - 1);
-                return Some(output);
+        self.next()
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.indexes.remaining() {
+            Some(remaining) if remaining <= usize::MAX as u128 => {
+                (remaining as usize, Some(remaining as usize))
             }
+            _ => (usize::MAX, None),
+        }
+    }
+
+    fn count(self) -> usize {
+        match self.size_hint() {
+            (count, Some(_)) => count,
+            _ => panic!("the number of permutations exceeds a usize"),
         }
     }
 
+    fn last(mut self) -> Option<Self::Item> {
+        // The last permutation that remains is the one that the back indexes point to.
+        self.next_back()
+    }
+
     fn next(&mut self) -> Option<Self::Item> {
         // Without this check, the permutator would cycle forever and never return `None`
         // because my incrementing algorithim prohibits it.
-        if self.indexes.max_iters != 0 && self.indexes.curr_iter == self.indexes.max_iters {
+        if self.indexes.is_exhausted() {
             return None;
         }
 
         self.indexes.curr_iter += 1;
         // Generates the next permutation sequence using the current indexes.
-        let output = ListWrap::next_item(&self.lists, &self.indexes.indexes);
+        let output = ListWrap::next_item(&self.lists, self.indexes.indexes.as_ref());
 
         // Increment the indexes to point towards the next set of values.
         self.indexes.increment(self.lists.wrapper_len() - 1);
@@ -177,3 +730,37 @@ where
         Some(output)
     }
 }
+
+/// `len()` panics if the number of remaining permutations does not fit within a `usize`.
+impl<ListWrap, ItemWrap> ExactSizeIterator for Permutator<ListWrap, ItemWrap>
+where
+    ListWrap: ListWrapper<ItemWrap>,
+{
+    fn len(&self) -> usize {
+        match self.size_hint() {
+            (len, Some(_)) => len,
+            _ => panic!("the number of permutations exceeds a usize"),
+        }
+    }
+}
+
+impl<ListWrap, ItemWrap> DoubleEndedIterator for Permutator<ListWrap, ItemWrap>
+where
+    ListWrap: ListWrapper<ItemWrap>,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        // The front and back share the same count of permutations, so they meet in the middle.
+        if self.indexes.is_exhausted() {
+            return None;
+        }
+
+        self.indexes.back_iter += 1;
+        // Generates the previous permutation sequence using the current back indexes.
+        let output = ListWrap::next_item(&self.lists, self.indexes.back_indexes.as_ref());
+
+        // Decrement the back indexes to point towards the previous set of values.
+        self.indexes.decrement(self.lists.wrapper_len() - 1);
+
+        Some(output)
+    }
+}
@@ -1,9 +1,20 @@
+mod by_clone;
+mod by_ref;
+mod fixed;
+mod optional;
+mod owned_lists;
 mod single_list;
 mod tuple_of_lists;
 mod vec_of_lists;
 
+use IndexStorage;
+
 // re-export
-pub use self::single_list::Repeated;
+pub use self::by_clone::ByClone;
+pub use self::by_ref::ByRef;
+pub use self::fixed::Fixed;
+pub use self::optional::Optional;
+pub use self::single_list::{Repeated, RepeatedLen};
 
 /// Abstract the outermost slice(s) wrapper behaviour.  
 /// eg. Accesses on `Vec` slice wrappers are different from `tuple` slice wrappers.
@@ -11,12 +22,32 @@ pub trait ListWrapper<ItemWrap>
 where
     ItemWrap: Sized,
 {
+    /// The storage of the index into each slice, which is a `Vec<usize>` unless the number
+    /// of slices is known at compile time.
+    type Indexes: IndexStorage;
     /// The total number of slices that are being permutated with.
     fn wrapper_len(&self) -> usize;
     /// The length of each slices that are being permutated with.
     fn lens(&self) -> Vec<usize>;
     /// Get values according to the given `indexes`.
-    fn next_item(&self, indexes: &Vec<usize>) -> ItemWrap;
+    ///
+    /// # Panics
+    /// This method will panic if any of the `indexes` are out of bounds of their slice.
+    fn next_item(&self, indexes: &[usize]) -> ItemWrap;
     /// Same as `next_item`, but doesn't allocates for the returning `ItemWrap`.
-    fn next_with_buffer(&self, indexes: &Vec<usize>, buffer: &mut ItemWrap) -> ();
+    ///
+    /// # Panics
+    /// This method will panic if any of the `indexes` are out of bounds of their slice, or
+    /// if the `buffer` is not large enough to contain the permutation.
+    fn next_with_buffer(&self, indexes: &[usize], buffer: &mut ItemWrap) -> ();
+}
+
+/// Locates the values of a permutation within the slices that they originate from.
+pub trait ListLookup<ItemWrap>: ListWrapper<ItemWrap>
+where
+    ItemWrap: Sized,
+{
+    /// Get the `indexes` at which each value of `item` is found, using the first match
+    /// in each slice. Returns `None` if any value is not present in its slice.
+    fn indexes_of(&self, item: &ItemWrap) -> Option<Vec<usize>>;
 }
@@ -0,0 +1,88 @@
+use std::sync::Arc;
+
+use {ListLookup, ListWrapper};
+
+/// Indicates that the values of the wrapped lists are yielded as clones, rather than being
+/// copied out of the lists, so that lists of values which are `Clone` but not `Copy`, such as
+/// `String`s, may be permutated into owned permutations. A vector of owned `Vec<T>` or
+/// `Arc<[T]>` lists may be wrapped as well, so that the `Permutator` owns its lists.
+///
+/// # Example
+///
+/// ```rust
+/// # use permutate::{ByClone, Permutator, PermutatorWrapper as _};
+/// #
+/// let names = [String::from("ann"), String::from("bob")];
+/// let sizes = [vec![1, 2], vec![3]];
+/// let lists = ByClone((&names[..], &sizes[..]));
+/// let permutator = Permutator::new(&lists);
+///
+/// let output = [
+///   (String::from("ann"), vec![1, 2]), (String::from("ann"), vec![3]),
+///   (String::from("bob"), vec![1, 2]), (String::from("bob"), vec![3]),
+/// ];
+/// #
+/// # permutator.zip(output[..].iter()).for_each(|(p, o)| assert_eq!(&p, o));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct ByClone<L>(pub L);
+
+// implementations for vectors of borrowed or owned lists, yielding clones, where the owned
+// lists allow a `Permutator` of values that are not `Copy`, such as `String`s, to be stored,
+// returned, or sent to another thread
+macro_rules! by_clone_impls {
+    ($($List:ty),+) => {
+        $(
+            impl<T> ListWrapper<Vec<T>> for ByClone<Vec<$List>>
+            where
+                T: Clone,
+            {
+                type Indexes = Vec<usize>;
+                fn wrapper_len(&self) -> usize {
+                    self.0.len()
+                }
+                fn lens(&self) -> Vec<usize> {
+                    self.0.iter().map(|list| list.len()).collect::<Vec<usize>>()
+                }
+                fn next_item(&self, indexes: &[usize]) -> Vec<T> {
+                    indexes
+                        .iter()
+                        .enumerate()
+                        .map(|(list, value)| self.0[list][*value].clone())
+                        .collect::<Vec<T>>()
+                }
+                fn next_with_buffer(&self, indexes: &[usize], buffer: &mut Vec<T>) {
+                    assert!(
+                        buffer.len() >= self.wrapper_len(),
+                        "buffer is not large enough to contain the permutation"
+                    );
+
+                    // The previous values are cloned into, so that their allocations may be
+                    // re-used.
+                    for (list, value) in indexes.iter().enumerate() {
+                        buffer[list].clone_from(&self.0[list][*value]);
+                    }
+                }
+            }
+
+            impl<T> ListLookup<Vec<T>> for ByClone<Vec<$List>>
+            where
+                T: Clone + PartialEq,
+            {
+                fn indexes_of(&self, item: &Vec<T>) -> Option<Vec<usize>> {
+                    if item.len() != self.0.len() {
+                        return None;
+                    }
+
+                    self.0
+                        .iter()
+                        .zip(item.iter())
+                        .map(|(list, value)| list.iter().position(|v| v == value))
+                        .collect()
+                }
+            }
+        )+
+    };
+}
+
+by_clone_impls!(&[T], Vec<T>, Arc<[T]>);
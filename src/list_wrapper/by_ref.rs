@@ -0,0 +1,70 @@
+use {ListLookup, ListWrapper};
+
+/// Indicates that the values of the wrapped lists are yielded as references into the lists,
+/// rather than being copied out of them, so that lists of values which are not `Copy`, such
+/// as `String`s or structs, may be permutated directly.
+///
+/// # Example
+///
+/// ```rust
+/// # use permutate::{ByRef, Permutator, PermutatorWrapper as _};
+/// #
+/// let names = [String::from("ann"), String::from("bob")];
+/// let roles = [String::from("author"), String::from("reviewer")];
+/// let lists = ByRef(vec![&names[..], &roles[..]]);
+/// let permutator = Permutator::new(&lists);
+///
+/// let output = [
+///   [&names[0], &roles[0]], [&names[0], &roles[1]],
+///   [&names[1], &roles[0]], [&names[1], &roles[1]],
+/// ];
+/// #
+/// # permutator.zip(output[..].iter()).for_each(|(p, o)| assert_eq!(&p, o));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct ByRef<L>(pub L);
+
+// implementation for lists of lists, yielding references
+impl<'a, T> ListWrapper<Vec<&'a T>> for ByRef<Vec<&'a [T]>> {
+    type Indexes = Vec<usize>;
+    fn wrapper_len(&self) -> usize {
+        self.0.len()
+    }
+    fn lens(&self) -> Vec<usize> {
+        self.0.iter().map(|list| list.len()).collect::<Vec<usize>>()
+    }
+    fn next_item(&self, indexes: &[usize]) -> Vec<&'a T> {
+        indexes
+            .iter()
+            .enumerate()
+            .map(|(list, value)| &self.0[list][*value])
+            .collect::<Vec<&'a T>>()
+    }
+    fn next_with_buffer(&self, indexes: &[usize], buffer: &mut Vec<&'a T>) {
+        assert!(
+            buffer.len() >= self.wrapper_len(),
+            "buffer is not large enough to contain the permutation"
+        );
+
+        for (list, value) in indexes.iter().enumerate() {
+            buffer[list] = &self.0[list][*value];
+        }
+    }
+}
+
+impl<'a, T> ListLookup<Vec<&'a T>> for ByRef<Vec<&'a [T]>>
+where
+    T: PartialEq,
+{
+    fn indexes_of(&self, item: &Vec<&'a T>) -> Option<Vec<usize>> {
+        if item.len() != self.0.len() {
+            return None;
+        }
+
+        self.0
+            .iter()
+            .zip(item.iter())
+            .map(|(list, value)| list.iter().position(|v| v == *value))
+            .collect()
+    }
+}
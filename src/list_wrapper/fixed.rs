@@ -0,0 +1,59 @@
+use std::array;
+use {ListLookup, ListWrapper};
+
+/// Indicates that a fixed number of lists, `N`, are permutated, so that each permutation may
+/// be yielded as a `[T; N]` array on the stack, rather than as a `Vec<T>` on the heap. The
+/// permutator keeps the index of each list within a `[usize; N]` array as well.
+///
+/// # Example
+///
+/// ```rust
+/// # use permutate::{Fixed, Permutator, PermutatorWrapper as _};
+/// #
+/// let lists = Fixed([&["a", "b"][..], &["1", "2"][..], &["x"][..]]);
+/// let permutator = Permutator::new(&lists);
+///
+/// let output = [
+///   ["a", "1", "x"], ["a", "2", "x"],
+///   ["b", "1", "x"], ["b", "2", "x"],
+/// ];
+/// #
+/// # assert_eq!(permutator.collect::<Vec<_>>(), output);
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Fixed<L>(pub L);
+
+// implementation for an array of lists, yielding arrays
+impl<T, const N: usize> ListWrapper<[T; N]> for Fixed<[&[T]; N]>
+where
+    T: Copy,
+{
+    type Indexes = [usize; N];
+    fn wrapper_len(&self) -> usize {
+        N
+    }
+    fn lens(&self) -> Vec<usize> {
+        self.0.iter().map(|list| list.len()).collect::<Vec<usize>>()
+    }
+    fn next_item(&self, indexes: &[usize]) -> [T; N] {
+        array::from_fn(|list| self.0[list][indexes[list]])
+    }
+    fn next_with_buffer(&self, indexes: &[usize], buffer: &mut [T; N]) {
+        for ((value, list), index) in buffer.iter_mut().zip(self.0.iter()).zip(indexes.iter()) {
+            *value = list[*index];
+        }
+    }
+}
+
+impl<T, const N: usize> ListLookup<[T; N]> for Fixed<[&[T]; N]>
+where
+    T: Copy + PartialEq,
+{
+    fn indexes_of(&self, item: &[T; N]) -> Option<Vec<usize>> {
+        self.0
+            .iter()
+            .zip(item.iter())
+            .map(|(list, value)| list.iter().position(|v| v == value))
+            .collect()
+    }
+}
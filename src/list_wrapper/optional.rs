@@ -0,0 +1,95 @@
+use {ListLookup, ListWrapper};
+
+/// Indicates that each of the wrapped lists has an additional, virtual "absent" value, which
+/// is yielded as `None`, while the values of the lists are yielded as `Some`. The absent value
+/// precedes the values of its list, and empty lists are permitted, as they will always be
+/// absent.
+///
+/// # Example
+///
+/// ```rust
+/// # use permutate::{Optional, Permutator, PermutatorWrapper as _};
+/// #
+/// let lists = Optional(vec![&["on", "off"][..], &[][..]]);
+/// let permutator = Permutator::new(&lists);
+///
+/// let output = [
+///   &[None, None][..],
+///   &[Some("on"), None][..],
+///   &[Some("off"), None][..],
+/// ];
+/// #
+/// # permutator.zip(output[..].iter()).for_each(|(p, o)| assert_eq!(&p, o));
+/// ```
+#[derive(Clone, Copy, Debug)]
+pub struct Optional<L>(pub L);
+
+/// Obtains the value that `index` points to, where `0` is the absent value.
+#[inline]
+pub(crate) fn optional_value<T: Copy>(list: &[T], index: usize) -> Option<T> {
+    if index == 0 {
+        None
+    } else {
+        Some(list[index - 1])
+    }
+}
+
+/// Obtains the index of `value` within `list`, where `0` is the absent value.
+#[inline]
+pub(crate) fn optional_index<T: PartialEq>(list: &[T], value: &Option<T>) -> Option<usize> {
+    match *value {
+        Some(ref value) => list.iter().position(|v| v == value).map(|index| index + 1),
+        None => Some(0),
+    }
+}
+
+// implementation for lists of optional lists
+impl<T> ListWrapper<Vec<Option<T>>> for Optional<Vec<&[T]>>
+where
+    T: Copy,
+{
+    type Indexes = Vec<usize>;
+    fn wrapper_len(&self) -> usize {
+        self.0.len()
+    }
+    fn lens(&self) -> Vec<usize> {
+        self.0
+            .iter()
+            .map(|list| list.len() + 1)
+            .collect::<Vec<usize>>()
+    }
+    fn next_item(&self, indexes: &[usize]) -> Vec<Option<T>> {
+        indexes
+            .iter()
+            .enumerate()
+            .map(|(list, value)| optional_value(self.0[list], *value))
+            .collect::<Vec<Option<T>>>()
+    }
+    fn next_with_buffer(&self, indexes: &[usize], buffer: &mut Vec<Option<T>>) {
+        assert!(
+            buffer.len() >= self.wrapper_len(),
+            "buffer is not large enough to contain the permutation"
+        );
+
+        for (list, value) in indexes.iter().enumerate() {
+            buffer[list] = optional_value(self.0[list], *value);
+        }
+    }
+}
+
+impl<T> ListLookup<Vec<Option<T>>> for Optional<Vec<&[T]>>
+where
+    T: Copy + PartialEq,
+{
+    fn indexes_of(&self, item: &Vec<Option<T>>) -> Option<Vec<usize>> {
+        if item.len() != self.0.len() {
+            return None;
+        }
+
+        self.0
+            .iter()
+            .zip(item.iter())
+            .map(|(list, value)| optional_index(list, value))
+            .collect()
+    }
+}
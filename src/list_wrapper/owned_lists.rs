@@ -0,0 +1,59 @@
+use std::sync::Arc;
+
+use {ListLookup, ListWrapper};
+
+// implementations for vectors of owned lists, which do not borrow from the caller, so that a
+// `Permutator` of them may be stored, returned, or sent to another thread
+macro_rules! owned_impls {
+    ($($List:ty),+) => {
+        $(
+            impl<T> ListWrapper<Vec<T>> for Vec<$List>
+            where
+                T: Copy,
+            {
+                type Indexes = Vec<usize>;
+                fn wrapper_len(&self) -> usize {
+                    self.len()
+                }
+                fn lens(&self) -> Vec<usize> {
+                    self.iter().map(|list| list.len()).collect::<Vec<usize>>()
+                }
+                fn next_item(&self, indexes: &[usize]) -> Vec<T> {
+                    indexes
+                        .iter()
+                        .enumerate()
+                        .map(|(list, value)| self[list][*value])
+                        .collect::<Vec<T>>()
+                }
+                fn next_with_buffer(&self, indexes: &[usize], buffer: &mut Vec<T>) {
+                    assert!(
+                        buffer.len() >= self.wrapper_len(),
+                        "buffer is not large enough to contain the permutation"
+                    );
+
+                    for (list, value) in indexes.iter().enumerate() {
+                        buffer[list] = self[list][*value];
+                    }
+                }
+            }
+
+            impl<T> ListLookup<Vec<T>> for Vec<$List>
+            where
+                T: Copy + PartialEq,
+            {
+                fn indexes_of(&self, item: &Vec<T>) -> Option<Vec<usize>> {
+                    if item.len() != self.len() {
+                        return None;
+                    }
+
+                    self.iter()
+                        .zip(item.iter())
+                        .map(|(list, value)| list.iter().position(|v| v == value))
+                        .collect()
+                }
+            }
+        )+
+    };
+}
+
+owned_impls!(Vec<T>, Arc<[T]>);
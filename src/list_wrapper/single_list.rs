@@ -1,4 +1,4 @@
-use ListWrapper;
+use {ListLookup, ListWrapper};
 
 type OneSized<'a, T> = [&'a [T]; 1];
 
@@ -54,38 +54,145 @@ impl<'a, T> ListWrapper<Vec<T>> for OneSized<'a, T>
 where
     T: ?Sized + Copy,
 {
+    type Indexes = Vec<usize>;
     fn wrapper_len(&self) -> usize {
-        let len = self[0].len();
-        debug_assert!(len != 0);
-        len
+        self[0].len()
     }
     fn lens(&self) -> Vec<usize> {
         let nlists = self[0].len();
-        debug_assert!(nlists != 0);
         (0..nlists).map(|_| nlists).collect::<Vec<usize>>()
     }
-    fn next_item(&self, indexes: &Vec<usize>) -> Vec<T> {
-        // We are using `get_unchecked()` here because the incrementing
-        // algorithim prohibits values from being out of bounds.
+    fn next_item(&self, indexes: &[usize]) -> Vec<T> {
         indexes
             .iter()
-            .map(|value| unsafe { *self[0].get_unchecked(*value) })
+            .map(|value| self[0][*value])
             .collect::<Vec<T>>()
     }
-    fn next_with_buffer(&self, indexes: &Vec<usize>, buffer: &mut Vec<T>) -> () {
-        debug_assert!(
+    fn next_with_buffer(&self, indexes: &[usize], buffer: &mut Vec<T>) -> () {
+        assert!(
             buffer.len() >= self.wrapper_len(),
-            "bufferis not large enough to contain the permutation"
+            "buffer is not large enough to contain the permutation"
         );
 
-        let mut index = 0;
-        // We are using `get_unchecked()` here because the incrementing
-        // algorithim prohibits values from being out of bounds.
-        unsafe {
-            for outer_value in indexes.iter().map(|value| *self[0].get_unchecked(*value)) {
-                *buffer.get_unchecked_mut(index) = outer_value;
-                index += 1;
-            }
-        };
+        for (index, value) in indexes.iter().enumerate() {
+            buffer[index] = self[0][*value];
+        }
+    }
+}
+
+impl<'a, T> ListLookup<Vec<T>> for OneSized<'a, T>
+where
+    T: Copy + PartialEq,
+{
+    fn indexes_of(&self, item: &Vec<T>) -> Option<Vec<usize>> {
+        if item.len() != self.wrapper_len() {
+            return None;
+        }
+
+        item.iter()
+            .map(|value| self[0].iter().position(|v| v == value))
+            .collect()
+    }
+}
+
+/// Indicates that the permutator will repeat the input list a given number of times,
+/// generating every word of that length over the list (`n^k` permutations), rather than
+/// words as long as the list itself.
+///
+/// # Example
+///
+/// ```rust
+/// # use permutate::{Permutator, PermutatorWrapper as _, RepeatedLen};
+/// #
+/// let list = &["a", "b", "c"][..];
+/// let permutator = Permutator::new(&RepeatedLen::new(list, 2));
+///
+/// let output = [
+///   &["a", "a"][..], &["a", "b"][..], &["a", "c"][..],
+///   &["b", "a"][..], &["b", "b"][..], &["b", "c"][..],
+///   &["c", "a"][..], &["c", "b"][..], &["c", "c"][..],
+/// ];
+/// #
+/// # permutator.zip(output[..].iter()).for_each(|(p, o)| assert_eq!(&p, o));
+/// ```
+#[derive(Debug)]
+pub struct RepeatedLen<'a, T: 'a> {
+    list: &'a [T],
+    len: usize,
+}
+
+// `Clone` and `Copy` are implemented by hand, as only a reference to the values is held.
+impl<'a, T> Clone for RepeatedLen<'a, T> {
+    fn clone(&self) -> RepeatedLen<'a, T> {
+        *self
+    }
+}
+
+impl<'a, T> Copy for RepeatedLen<'a, T> {}
+
+impl<'a, T> RepeatedLen<'a, T> {
+    /// Repeats the `list` for words of `len` values.
+    pub fn new(list: &'a [T], len: usize) -> RepeatedLen<'a, T> {
+        RepeatedLen { list, len }
+    }
+
+    /// The list that the words are generated from.
+    pub fn list(&self) -> &'a [T] {
+        self.list
+    }
+
+    /// The number of values within each word.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Whether the words contain no values.
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+}
+
+// implementation for when it's a single list with an explicit length
+impl<'a, T> ListWrapper<Vec<T>> for RepeatedLen<'a, T>
+where
+    T: Copy,
+{
+    type Indexes = Vec<usize>;
+    fn wrapper_len(&self) -> usize {
+        self.len
+    }
+    fn lens(&self) -> Vec<usize> {
+        vec![self.list.len(); self.len]
+    }
+    fn next_item(&self, indexes: &[usize]) -> Vec<T> {
+        indexes
+            .iter()
+            .map(|value| self.list[*value])
+            .collect::<Vec<T>>()
+    }
+    fn next_with_buffer(&self, indexes: &[usize], buffer: &mut Vec<T>) {
+        assert!(
+            buffer.len() >= self.wrapper_len(),
+            "buffer is not large enough to contain the permutation"
+        );
+
+        for (index, value) in indexes.iter().enumerate() {
+            buffer[index] = self.list[*value];
+        }
+    }
+}
+
+impl<'a, T> ListLookup<Vec<T>> for RepeatedLen<'a, T>
+where
+    T: Copy + PartialEq,
+{
+    fn indexes_of(&self, item: &Vec<T>) -> Option<Vec<usize>> {
+        if item.len() != self.len {
+            return None;
+        }
+
+        item.iter()
+            .map(|value| self.list.iter().position(|v| v == value))
+            .collect()
     }
 }
@@ -1,4 +1,5 @@
-use ListWrapper;
+use super::optional::{optional_index, optional_value};
+use {ByClone, ByRef, ListLookup, ListWrapper, Optional};
 
 // reference: https://doc.rust-lang.org/src/core/tuple.rs.html
 macro_rules! tuple_impls {
@@ -13,44 +14,155 @@ macro_rules! tuple_impls {
                 $($T: ?Sized + Copy,)+
                 // &'a Self: Sized,
             {
+                type Indexes = Vec<usize>;
                 fn wrapper_len(&self) -> usize {
                     tuple_impls!(@last_idx $($idx,)+) + 1
                 }
                 fn lens(&self) -> Vec<usize> {
-                    let ls = vec![$(self.$idx.len()),+];
-                    ls.iter().for_each(|l| debug_assert!(*l != 0));
-                    ls
-                }
-                fn next_item(&self, indexes: &Vec<usize>) -> ($($T,)+) {
-                    // We are using `get_unchecked()` here because the incrementing
-                    // algorithim prohibits values from being out of bounds.
-                    unsafe {
-                        (
-                            $(*self.$idx.get_unchecked(indexes[$idx]),)+
-                        )
-                    }
+                    vec![$(self.$idx.len()),+]
+                }
+                fn next_item(&self, indexes: &[usize]) -> ($($T,)+) {
+                    (
+                        $(self.$idx[indexes[$idx]],)+
+                    )
                 }
 
 
                 fn next_with_buffer(
                     &self,
-                    indexes: &Vec<usize>,
+                    indexes: &[usize],
                     buffer: &mut ($($T,)+),
                 ) -> () {
                     // `nlists` verification is unnecessary because it's verified
                     // at compile-time
 
-                    // We are using `get_unchecked()` here because the incrementing
-                    // algorithim prohibits values from being out of bounds.
-                    unsafe {
-                        $(
-                            buffer.$idx = *self.$idx.get_unchecked(indexes[$idx]);
-                        )+
-                    }
+                    $(
+                        buffer.$idx = self.$idx[indexes[$idx]];
+                    )+
                 }
             }
 
+            impl<'a, $($T),+> ListLookup<($($T,)+)> for ($(&'a [$T],)+)
+            where
+                $($T: Copy + PartialEq,)+
+            {
+                fn indexes_of(&self, item: &($($T,)+)) -> Option<Vec<usize>> {
+                    Some(vec![
+                        $(self.$idx.iter().position(|v| *v == item.$idx)?,)+
+                    ])
+                }
+            }
 
+            impl<'a, $($T),+> ListWrapper<($(Option<$T>,)+)> for Optional<($(&'a [$T],)+)>
+            where
+                $($T: Copy,)+
+            {
+                type Indexes = Vec<usize>;
+                fn wrapper_len(&self) -> usize {
+                    tuple_impls!(@last_idx $($idx,)+) + 1
+                }
+                fn lens(&self) -> Vec<usize> {
+                    vec![$((self.0).$idx.len() + 1),+]
+                }
+                fn next_item(&self, indexes: &[usize]) -> ($(Option<$T>,)+) {
+                    (
+                        $(optional_value((self.0).$idx, indexes[$idx]),)+
+                    )
+                }
+                fn next_with_buffer(
+                    &self,
+                    indexes: &[usize],
+                    buffer: &mut ($(Option<$T>,)+),
+                ) {
+                    $(
+                        buffer.$idx = optional_value((self.0).$idx, indexes[$idx]);
+                    )+
+                }
+            }
+
+            impl<'a, $($T),+> ListLookup<($(Option<$T>,)+)> for Optional<($(&'a [$T],)+)>
+            where
+                $($T: Copy + PartialEq,)+
+            {
+                fn indexes_of(&self, item: &($(Option<$T>,)+)) -> Option<Vec<usize>> {
+                    Some(vec![
+                        $(optional_index((self.0).$idx, &item.$idx)?,)+
+                    ])
+                }
+            }
+
+            impl<'a, $($T),+> ListWrapper<($(&'a $T,)+)> for ByRef<($(&'a [$T],)+)> {
+                type Indexes = Vec<usize>;
+                fn wrapper_len(&self) -> usize {
+                    tuple_impls!(@last_idx $($idx,)+) + 1
+                }
+                fn lens(&self) -> Vec<usize> {
+                    vec![$((self.0).$idx.len()),+]
+                }
+                fn next_item(&self, indexes: &[usize]) -> ($(&'a $T,)+) {
+                    (
+                        $(&(self.0).$idx[indexes[$idx]],)+
+                    )
+                }
+                fn next_with_buffer(
+                    &self,
+                    indexes: &[usize],
+                    buffer: &mut ($(&'a $T,)+),
+                ) {
+                    $(
+                        buffer.$idx = &(self.0).$idx[indexes[$idx]];
+                    )+
+                }
+            }
+
+            impl<'a, $($T),+> ListLookup<($(&'a $T,)+)> for ByRef<($(&'a [$T],)+)>
+            where
+                $($T: PartialEq,)+
+            {
+                fn indexes_of(&self, item: &($(&'a $T,)+)) -> Option<Vec<usize>> {
+                    Some(vec![
+                        $((self.0).$idx.iter().position(|v| v == item.$idx)?,)+
+                    ])
+                }
+            }
+
+            impl<'a, $($T),+> ListWrapper<($($T,)+)> for ByClone<($(&'a [$T],)+)>
+            where
+                $($T: Clone,)+
+            {
+                type Indexes = Vec<usize>;
+                fn wrapper_len(&self) -> usize {
+                    tuple_impls!(@last_idx $($idx,)+) + 1
+                }
+                fn lens(&self) -> Vec<usize> {
+                    vec![$((self.0).$idx.len()),+]
+                }
+                fn next_item(&self, indexes: &[usize]) -> ($($T,)+) {
+                    (
+                        $((self.0).$idx[indexes[$idx]].clone(),)+
+                    )
+                }
+                fn next_with_buffer(
+                    &self,
+                    indexes: &[usize],
+                    buffer: &mut ($($T,)+),
+                ) {
+                    $(
+                        buffer.$idx.clone_from(&(self.0).$idx[indexes[$idx]]);
+                    )+
+                }
+            }
+
+            impl<'a, $($T),+> ListLookup<($($T,)+)> for ByClone<($(&'a [$T],)+)>
+            where
+                $($T: Clone + PartialEq,)+
+            {
+                fn indexes_of(&self, item: &($($T,)+)) -> Option<Vec<usize>> {
+                    Some(vec![
+                        $((self.0).$idx.iter().position(|v| *v == item.$idx)?,)+
+                    ])
+                }
+            }
         )+
     };
 
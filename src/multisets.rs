@@ -0,0 +1,274 @@
+use std::marker::PhantomData;
+
+use combinations::{self, binomial};
+use progress::Progress;
+use {ListWrapper, PermutateError, PermutatorWrapper, RepeatedLen};
+
+/// Generates the `(n + k - 1) choose k` multisets of `k` values taken from a list of `n`
+/// values, where each value may be taken more than once and the order of the values within a
+/// multiset is irrelevant. This is the unordered counterpart of `RepeatedLen`: the index
+/// vectors are non-decreasing, and are generated in lexicographic order.
+///
+/// # Example
+///
+/// ```rust
+/// # use permutate::{Multisets, PermutatorWrapper as _, RepeatedLen};
+/// #
+/// let list = &["a", "b", "c"][..];
+/// let multisets = Multisets::new(&RepeatedLen::new(list, 2));
+///
+/// let output = [
+///   &["a", "a"][..], &["a", "b"][..], &["a", "c"][..],
+///   &["b", "b"][..], &["b", "c"][..], &["c", "c"][..],
+/// ];
+/// #
+/// # assert_eq!(multisets.collect::<Vec<_>>(), output);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Multisets<'a, T: 'a> {
+    /// The list and the number of values to take from it.
+    lists: RepeatedLen<'a, T>,
+    /// The non-decreasing indexes that point to the next multiset from the front.
+    indexes: Vec<usize>,
+    /// The non-decreasing indexes that point to the next multiset from the back.
+    back_indexes: Vec<usize>,
+    /// The number of items generated from the front and back, out of the total.
+    progress: Progress,
+    _list_item_wrapper: PhantomData<T>,
+}
+
+/// Moves the non-decreasing `multiset` of `0..n` towards the next lexicographic multiset.
+fn next_multiset(multiset: &mut [usize], n: usize) {
+    if let Some(i) = multiset.iter().rposition(|&index| index + 1 < n) {
+        let value = multiset[i] + 1;
+        multiset[i..].iter_mut().for_each(|index| *index = value);
+    }
+}
+
+/// Moves the non-decreasing `multiset` of `0..n` towards the previous lexicographic multiset.
+fn prev_multiset(multiset: &mut [usize], n: usize) {
+    let lower = |multiset: &[usize], i: usize| if i == 0 { 0 } else { multiset[i - 1] };
+    if let Some(i) = (0..multiset.len()).rposition(|i| multiset[i] > lower(multiset, i)) {
+        multiset[i] -= 1;
+        multiset[i + 1..]
+            .iter_mut()
+            .for_each(|index| *index = n - 1);
+    }
+}
+
+/// Spreads the non-decreasing `multiset` into a strictly ascending combination of
+/// `0..n + k - 1`, which preserves the lexicographic order.
+fn to_combination(multiset: &[usize]) -> Vec<usize> {
+    multiset
+        .iter()
+        .enumerate()
+        .map(|(i, &index)| index + i)
+        .collect()
+}
+
+/// Ensures that the `indexes` are a non-decreasing selection of `k` values out of `n`.
+fn validate(indexes: &[usize], n: usize, k: usize) -> Result<(), PermutateError> {
+    if indexes.len() != k {
+        return Err(PermutateError::InvalidLength(k, indexes.len()));
+    }
+    match (0..k).find(|&i| indexes[i] >= n || (i != 0 && indexes[i] < indexes[i - 1])) {
+        Some(list) => Err(PermutateError::IndexOutOfBounds(list)),
+        None => Ok(()),
+    }
+}
+
+impl<'a, T> Multisets<'a, T> {
+    fn len_and_k(&self) -> (usize, usize) {
+        (self.lists.list().len(), self.lists.len())
+    }
+
+    fn unrank(&self, rank: u128, indexes: &mut [usize]) {
+        let (n, k) = self.len_and_k();
+        combinations::unrank(rank, n + k - 1, self.progress.total(), indexes);
+        for (i, index) in indexes.iter_mut().enumerate() {
+            *index -= i;
+        }
+    }
+
+    /// Computes the lexicographic position of the multiset that the given non-decreasing
+    /// `indexes` point to.
+    ///
+    /// # Panics
+    /// This method will panic if the indexes vector is not the correct length, if the indexes
+    /// are not non-decreasing and within bounds, or if the number of multisets does not fit
+    /// within a `u128`.
+    pub fn rank_of(&self, indexes: &[usize]) -> u128 {
+        let (n, k) = self.len_and_k();
+        if let Err(why) = validate(indexes, n, k) {
+            panic!("{}", why);
+        }
+        combinations::rank(&to_combination(indexes), n + k - 1, self.progress.total())
+    }
+
+    /// Returns the multiset found at the given lexicographic position, without modifying the
+    /// state of the generator. Returns `None` if `rank` is out of range.
+    ///
+    /// # Panics
+    /// This method will panic if the number of multisets does not fit within a `u128`.
+    pub fn nth_multiset(&self, rank: u128) -> Option<Vec<T>>
+    where
+        T: Copy,
+    {
+        if rank >= self.progress.total() {
+            return None;
+        }
+
+        let mut indexes = vec![0; self.lists.len()];
+        self.unrank(rank, &mut indexes);
+        Some(self.lists.next_item(&indexes))
+    }
+
+    /// Moves the front of the generator to the given lexicographic position, so that the
+    /// following call to `next()` returns the multiset found at `rank`. Seeking to a rank at
+    /// or beyond the multisets that remain at the back exhausts the generator.
+    ///
+    /// # Panics
+    /// This method will panic if the number of multisets does not fit within a `u128`.
+    pub fn seek(&mut self, rank: u128) {
+        let end = self.progress.total() - self.progress.back_iter;
+        if rank >= end {
+            self.progress.curr_iter = end;
+            return;
+        }
+
+        let mut indexes = vec![0; self.lists.len()];
+        self.unrank(rank, &mut indexes);
+        self.indexes = indexes;
+        self.progress.curr_iter = rank;
+    }
+}
+
+impl<'a, T> PermutatorWrapper<RepeatedLen<'a, T>, Vec<T>> for Multisets<'a, T>
+where
+    T: Copy,
+{
+    /// Initialize a new `Multisets` for the list and `k` of the `RepeatedLen` wrapper.
+    ///
+    /// # Panics
+    /// This method will panic if `k` is `0`.
+    fn new(lists: &RepeatedLen<'a, T>) -> Multisets<'a, T> {
+        let (n, k) = (lists.list().len(), lists.len());
+        assert!(k != 0, "{}", PermutateError::NoLists);
+
+        Multisets {
+            lists: *lists,
+            indexes: vec![0; k],
+            back_indexes: vec![n.saturating_sub(1); k],
+            progress: Progress::new(
+                if n == 0 {
+                    Some(0)
+                } else {
+                    binomial(n + k - 1, k)
+                },
+                "multisets",
+            ),
+            _list_item_wrapper: PhantomData,
+        }
+    }
+
+    /// Sets the state of the front of the generator, where the `indexes` must be
+    /// non-decreasing.
+    fn set_index(&mut self, iter_no: u128, indexes: Vec<usize>) {
+        let (n, k) = self.len_and_k();
+        if let Err(why) = validate(&indexes, n, k) {
+            panic!("{}", why);
+        }
+        self.indexes = indexes;
+        self.progress.curr_iter = iter_no;
+    }
+
+    fn get_index(&self) -> (u128, Vec<usize>) {
+        (self.progress.curr_iter, self.indexes.clone())
+    }
+
+    fn max_permutations(&self) -> usize {
+        self.progress.total_usize()
+    }
+
+    fn checked_max_permutations(&self) -> Option<u128> {
+        self.progress.max_iters
+    }
+
+    fn reset(&mut self) {
+        *self = Multisets::new(&self.lists);
+    }
+
+    fn next_with_buffer<'b>(&mut self, buffer: &'b mut Vec<T>) -> Option<&'b mut Vec<T>> {
+        if self.progress.is_exhausted() {
+            return None;
+        }
+
+        self.progress.curr_iter += 1;
+        let (n, _) = self.len_and_k();
+        self.lists.next_with_buffer(&self.indexes, buffer);
+        next_multiset(&mut self.indexes, n);
+        Some(buffer)
+    }
+
+    fn next_back_with_buffer<'b>(&mut self, buffer: &'b mut Vec<T>) -> Option<&'b mut Vec<T>> {
+        if self.progress.is_exhausted() {
+            return None;
+        }
+
+        self.progress.back_iter += 1;
+        let (n, _) = self.len_and_k();
+        self.lists.next_with_buffer(&self.back_indexes, buffer);
+        prev_multiset(&mut self.back_indexes, n);
+        Some(buffer)
+    }
+}
+
+impl<'a, T> Iterator for Multisets<'a, T>
+where
+    T: Copy,
+{
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.progress.is_exhausted() {
+            return None;
+        }
+
+        self.progress.curr_iter += 1;
+        let (n, _) = self.len_and_k();
+        let output = self.lists.next_item(&self.indexes);
+        next_multiset(&mut self.indexes, n);
+        Some(output)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.progress.size_hint()
+    }
+}
+
+impl<'a, T> DoubleEndedIterator for Multisets<'a, T>
+where
+    T: Copy,
+{
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.progress.is_exhausted() {
+            return None;
+        }
+
+        self.progress.back_iter += 1;
+        let (n, _) = self.len_and_k();
+        let output = self.lists.next_item(&self.back_indexes);
+        prev_multiset(&mut self.back_indexes, n);
+        Some(output)
+    }
+}
+
+/// `len()` panics if the number of remaining multisets does not fit within a `usize`.
+impl<'a, T> ExactSizeIterator for Multisets<'a, T>
+where
+    T: Copy,
+{
+    fn len(&self) -> usize {
+        self.progress.len()
+    }
+}
@@ -0,0 +1,154 @@
+use rayon::iter::plumbing::{bridge, Consumer, Producer, ProducerCallback, UnindexedConsumer};
+use rayon::iter::{IndexedParallelIterator, IntoParallelIterator, ParallelIterator};
+use {ListWrapper, Permutator, PermutatorWrapper};
+
+/// A parallel iterator over the permutations of a `Permutator`, which is split into disjoint
+/// ranges of linear positions that are permutated on separate threads. The permutations are
+/// still collected in the order of the permutator.
+///
+/// # Example
+///
+/// ```rust
+/// # extern crate permutate;
+/// # extern crate rayon;
+/// # use permutate::{Permutator, PermutatorWrapper as _};
+/// # use rayon::prelude::*;
+/// #
+/// # fn main() {
+/// let lists = vec![&[1, 2, 3][..], &[4, 5, 6][..]];
+/// let sums: Vec<u32> = Permutator::new(&lists)
+///     .into_par_iter()
+///     .map(|values| values.iter().sum())
+///     .collect();
+/// assert_eq!(sums, [5, 6, 7, 6, 7, 8, 7, 8, 9]);
+/// # }
+/// ```
+#[derive(Debug)]
+pub struct ParallelPermutator<ListWrap, ItemWrap>
+where
+    ListWrap: ListWrapper<ItemWrap>,
+{
+    permutator: Permutator<ListWrap, ItemWrap>,
+}
+
+/// Produces the permutations of a range, and splits the range in two when rayon requests it.
+///
+/// # Panics
+/// Splitting the producer will panic if the number of permutations does not fit within a
+/// `u128`, as the permutations are then split by their linear positions.
+struct PermutatorProducer<ListWrap, ItemWrap>
+where
+    ListWrap: ListWrapper<ItemWrap>,
+{
+    permutator: Permutator<ListWrap, ItemWrap>,
+}
+
+impl<ListWrap, ItemWrap> IntoParallelIterator for Permutator<ListWrap, ItemWrap>
+where
+    ListWrap: ListWrapper<ItemWrap> + Clone + Send,
+    ItemWrap: Send,
+{
+    type Iter = ParallelPermutator<ListWrap, ItemWrap>;
+    type Item = ItemWrap;
+
+    /// Converts the permutations that remain between the front and back of the permutator
+    /// into a parallel iterator.
+    ///
+    /// # Panics
+    /// The parallel iterator will panic if the number of remaining permutations does not
+    /// fit within a `usize`.
+    fn into_par_iter(self) -> Self::Iter {
+        ParallelPermutator { permutator: self }
+    }
+}
+
+impl<ListWrap, ItemWrap> ParallelPermutator<ListWrap, ItemWrap>
+where
+    ListWrap: ListWrapper<ItemWrap> + Clone,
+{
+    /// The number of permutations that remain between the front and back of the permutator.
+    fn remaining(&self) -> usize {
+        let indexes = &self.permutator.indexes;
+        let max = self
+            .permutator
+            .checked_max_permutations()
+            .expect("the number of permutations exceeds a u128");
+        let remaining = max.saturating_sub(indexes.curr_iter + indexes.back_iter);
+        if remaining > usize::MAX as u128 {
+            panic!("the number of permutations exceeds a usize");
+        }
+        remaining as usize
+    }
+}
+
+impl<ListWrap, ItemWrap> ParallelIterator for ParallelPermutator<ListWrap, ItemWrap>
+where
+    ListWrap: ListWrapper<ItemWrap> + Clone + Send,
+    ItemWrap: Send,
+{
+    type Item = ItemWrap;
+
+    fn drive_unindexed<C>(self, consumer: C) -> C::Result
+    where
+        C: UnindexedConsumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    /// # Panics
+    /// This method will panic if the number of remaining permutations does not fit within a
+    /// `usize`.
+    fn opt_len(&self) -> Option<usize> {
+        Some(self.remaining())
+    }
+}
+
+impl<ListWrap, ItemWrap> IndexedParallelIterator for ParallelPermutator<ListWrap, ItemWrap>
+where
+    ListWrap: ListWrapper<ItemWrap> + Clone + Send,
+    ItemWrap: Send,
+{
+    /// # Panics
+    /// This method will panic if the number of remaining permutations does not fit within a
+    /// `usize`.
+    fn len(&self) -> usize {
+        self.remaining()
+    }
+
+    fn drive<C>(self, consumer: C) -> C::Result
+    where
+        C: Consumer<Self::Item>,
+    {
+        bridge(self, consumer)
+    }
+
+    fn with_producer<CB>(self, callback: CB) -> CB::Output
+    where
+        CB: ProducerCallback<Self::Item>,
+    {
+        callback.callback(PermutatorProducer {
+            permutator: self.permutator,
+        })
+    }
+}
+
+impl<ListWrap, ItemWrap> Producer for PermutatorProducer<ListWrap, ItemWrap>
+where
+    ListWrap: ListWrapper<ItemWrap> + Clone + Send,
+    ItemWrap: Send,
+{
+    type Item = ItemWrap;
+    type IntoIter = Permutator<ListWrap, ItemWrap>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.permutator
+    }
+
+    fn split_at(self, index: usize) -> (Self, Self) {
+        let (left, right) = self.permutator.split_after(index as u128);
+        (
+            PermutatorProducer { permutator: left },
+            PermutatorProducer { permutator: right },
+        )
+    }
+}
@@ -0,0 +1,160 @@
+use combinations::next_combination;
+use progress::Progress;
+use std::convert::TryFrom;
+
+/// The order in which `PowerSet` generates its subsets.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum PowerSetOrder {
+    /// Subsets are generated as a binary counter, where the first value of the list is the
+    /// least significant bit, so that the subset at position `r` contains the values whose
+    /// bits are set in `r`.
+    BinaryCounter,
+    /// Subsets are generated from the smallest to the largest, and subsets of the same size
+    /// are generated in lexicographic order of their indexes.
+    AscendingSize,
+}
+
+/// Generates every subset of a list, including the empty subset and the list itself, for a
+/// total of `2^n` subsets. The values of each subset retain the order of the list.
+///
+/// # Example
+///
+/// ```rust
+/// # use permutate::{PowerSet, PowerSetOrder};
+/// #
+/// let list = &["a", "b", "c"][..];
+///
+/// let subsets: Vec<Vec<&str>> = PowerSet::new(list).collect();
+/// assert_eq!(subsets, [
+///     &[][..], &["a"][..], &["b"][..], &["a", "b"][..],
+///     &["c"][..], &["a", "c"][..], &["b", "c"][..], &["a", "b", "c"][..],
+/// ]);
+///
+/// let subsets: Vec<Vec<&str>> = PowerSet::with_order(list, PowerSetOrder::AscendingSize)
+///     .collect();
+/// assert_eq!(subsets, [
+///     &[][..], &["a"][..], &["b"][..], &["c"][..],
+///     &["a", "b"][..], &["a", "c"][..], &["b", "c"][..], &["a", "b", "c"][..],
+/// ]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct PowerSet<'a, T: 'a> {
+    /// The list that the subsets are taken from.
+    list: &'a [T],
+    /// The order in which the subsets are generated.
+    order: PowerSetOrder,
+    /// The ascending indexes of the values within the next subset.
+    indexes: Vec<usize>,
+    /// The number of subsets that have been generated, out of the `2^n` possible.
+    progress: Progress,
+}
+
+impl<'a, T: Copy> PowerSet<'a, T> {
+    /// Initialize a new `PowerSet` that generates its subsets in binary counter order.
+    pub fn new(list: &'a [T]) -> PowerSet<'a, T> {
+        PowerSet::with_order(list, PowerSetOrder::BinaryCounter)
+    }
+
+    /// Initialize a new `PowerSet` that generates its subsets in the given `order`.
+    pub fn with_order(list: &'a [T], order: PowerSetOrder) -> PowerSet<'a, T> {
+        PowerSet {
+            list,
+            order,
+            indexes: Vec::with_capacity(list.len()),
+            progress: Progress::new(
+                u32::try_from(list.len())
+                    .ok()
+                    .and_then(|n| 1u128.checked_shl(n)),
+                "subsets",
+            ),
+        }
+    }
+
+    /// The order in which the subsets are generated.
+    pub fn order(&self) -> PowerSetOrder {
+        self.order
+    }
+
+    /// Returns the total number of subsets possible
+    ///
+    /// # Panics
+    /// This method will panic if the number of subsets does not fit within a `usize`.
+    pub fn max_permutations(&self) -> usize {
+        self.progress.total_usize()
+    }
+
+    /// Returns the total number of subsets possible, or `None` if the count does not fit
+    /// within a `u128`.
+    pub fn checked_max_permutations(&self) -> Option<u128> {
+        self.progress.max_iters
+    }
+
+    /// Resets the internal state to allow you to start generating subsets again.
+    pub fn reset(&mut self) {
+        self.indexes.clear();
+        self.progress.curr_iter = 0;
+    }
+
+    /// Provides similar functionality as the `Iterator` traits `next` method, but allows the
+    /// ability to re-use a prior allocation. The buffer is cleared and refilled with the values
+    /// of each subset, so its length varies between subsets.
+    pub fn next_with_buffer<'b>(&mut self, buffer: &'b mut Vec<T>) -> Option<&'b mut Vec<T>> {
+        if self.progress.is_exhausted() {
+            return None;
+        }
+
+        self.progress.curr_iter += 1;
+        buffer.clear();
+        buffer.extend(self.indexes.iter().map(|&index| self.list[index]));
+        self.advance();
+        Some(buffer)
+    }
+
+    fn advance(&mut self) {
+        let n = self.list.len();
+        match self.order {
+            PowerSetOrder::BinaryCounter => {
+                // Adding one clears the run of set bits at the bottom, and sets the next bit.
+                let carried = self
+                    .indexes
+                    .iter()
+                    .enumerate()
+                    .take_while(|&(bit, &index)| bit == index)
+                    .count();
+                self.indexes.drain(..carried);
+                self.indexes.insert(0, carried);
+            }
+            PowerSetOrder::AscendingSize => {
+                let k = self.indexes.len();
+                if self.indexes.first() == Some(&(n - k)) || k == 0 {
+                    // Every subset of this size has been generated, so move on to the next.
+                    self.indexes.clear();
+                    self.indexes.extend(0..k + 1);
+                } else {
+                    next_combination(&mut self.indexes, n);
+                }
+            }
+        }
+    }
+}
+
+impl<'a, T: Copy> Iterator for PowerSet<'a, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut output = Vec::with_capacity(self.indexes.len());
+        self.next_with_buffer(&mut output)?;
+        Some(output)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.progress.size_hint()
+    }
+}
+
+/// `len()` panics if the number of remaining subsets does not fit within a `usize`.
+impl<'a, T: Copy> ExactSizeIterator for PowerSet<'a, T> {
+    fn len(&self) -> usize {
+        self.progress.len()
+    }
+}
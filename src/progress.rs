@@ -0,0 +1,80 @@
+/// Tracks how many items a generator has produced from its front and its back, out of a total
+/// that may not fit within a `u128`. This is the counterpart of the iteration counts within
+/// `IndexCounters`, for the generators whose indexes are stepped by their own algorithms.
+#[derive(Clone, Copy, Debug)]
+pub struct Progress {
+    /// The current iteration position
+    pub curr_iter: u128,
+    /// The number of iterations performed from the back
+    pub back_iter: u128,
+    /// The maximum number of iterations to perform, or `None` if it exceeds a `u128`
+    pub max_iters: Option<u128>,
+    /// What the generated items are called within panic messages, such as "combinations".
+    noun: &'static str,
+}
+
+impl Progress {
+    pub fn new(max_iters: Option<u128>, noun: &'static str) -> Progress {
+        Progress {
+            curr_iter: 0,
+            back_iter: 0,
+            max_iters,
+            noun,
+        }
+    }
+
+    /// The number of items that remain between the front and back iterations, or `None` if
+    /// the total does not fit within a `u128`.
+    pub fn remaining(&self) -> Option<u128> {
+        self.max_iters
+            .map(|max| max.saturating_sub(self.curr_iter + self.back_iter))
+    }
+
+    /// Whether the front and back iterations have met, and thus no items remain.
+    pub fn is_exhausted(&self) -> bool {
+        self.remaining() == Some(0)
+    }
+
+    /// The total number of items.
+    ///
+    /// # Panics
+    /// This method will panic if the total does not fit within a `u128`.
+    pub fn total(&self) -> u128 {
+        match self.max_iters {
+            Some(max) => max,
+            None => panic!("the number of {} exceeds a u128", self.noun),
+        }
+    }
+
+    /// The total number of items.
+    ///
+    /// # Panics
+    /// This method will panic if the total does not fit within a `usize`.
+    pub fn total_usize(&self) -> usize {
+        match self.max_iters {
+            Some(max) if max <= usize::MAX as u128 => max as usize,
+            _ => panic!("the number of {} exceeds a usize", self.noun),
+        }
+    }
+
+    /// An exact size hint, unless the number of remaining items does not fit within a `usize`.
+    pub fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining() {
+            Some(remaining) if remaining <= usize::MAX as u128 => {
+                (remaining as usize, Some(remaining as usize))
+            }
+            _ => (usize::MAX, None),
+        }
+    }
+
+    /// The number of items that remain.
+    ///
+    /// # Panics
+    /// This method will panic if the number of remaining items does not fit within a `usize`.
+    pub fn len(&self) -> usize {
+        match self.size_hint() {
+            (len, Some(_)) => len,
+            _ => panic!("the number of {} exceeds a usize", self.noun),
+        }
+    }
+}
@@ -0,0 +1,205 @@
+use {ListWrapper, Permutator, ProductOrder};
+
+/// An iterator over the permutations of a `Permutator` whose every prefix is accepted by a
+/// predicate, which is created with `Permutator::with_prune()`.
+///
+/// Once the prefix of the first `d` lists is rejected, every permutation that shares it is
+/// skipped at once, rather than being generated and filtered after the fact. Prefixes that
+/// have not changed since they were last accepted are not checked again.
+pub struct Pruned<ListWrap, T, F>
+where
+    ListWrap: ListWrapper<Vec<T>>,
+{
+    permutator: Permutator<ListWrap, Vec<T>>,
+    /// The predicate that accepts or rejects each prefix.
+    keep: F,
+    /// The values of the next permutation, ordered from the slowest varying list.
+    prefix: Vec<T>,
+    /// The indexes that were last checked.
+    checked_indexes: Vec<usize>,
+    /// The number of lists whose prefix was accepted for the indexes that were last checked.
+    accepted: usize,
+}
+
+impl<ListWrap, T> Permutator<ListWrap, Vec<T>>
+where
+    ListWrap: ListWrapper<Vec<T>>,
+    T: Clone,
+{
+    /// Creates an iterator that only generates the permutations for which `keep` accepts
+    /// every prefix, where the prefixes hold the values of the first `1..=n` lists. When a
+    /// prefix is rejected, every permutation that shares it is skipped. With a
+    /// `DimensionPriority`, the prefixes are taken from the slowest varying list onwards.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use permutate::{Permutator, PermutatorWrapper as _};
+    /// #
+    /// let lists = vec![&["linux", "macos"][..], &["x86", "arm"][..], &["gcc", "clang"][..]];
+    /// let output: Vec<_> = Permutator::new(&lists)
+    ///     .with_prune(|prefix| prefix != ["macos", "x86"] && prefix != ["macos", "arm", "gcc"])
+    ///     .collect();
+    /// assert_eq!(output[4..], [["macos", "arm", "clang"]]);
+    /// ```
+    ///
+    /// # Panics
+    /// This method will panic if the permutator steps in a shuffled order, where the
+    /// permutations that share a prefix are not adjacent.
+    pub fn with_prune<F>(self, keep: F) -> Pruned<ListWrap, T, F>
+    where
+        F: FnMut(&[T]) -> bool,
+    {
+        if let ProductOrder::Shuffled(_) = self.indexes.order {
+            panic!("subtrees may not be skipped in a shuffled order");
+        }
+
+        let nlists = self.indexes.lens.as_ref().len();
+        Pruned {
+            permutator: self,
+            keep,
+            prefix: Vec::with_capacity(nlists),
+            checked_indexes: Vec::with_capacity(nlists),
+            accepted: 0,
+        }
+    }
+
+    /// Creates an iterator that only generates the permutations in which no value occurs in
+    /// more than one position. Each prefix is checked as it grows, so every permutation that
+    /// shares a prefix with a repeated value is skipped at once. Applied to a `Repeated` input,
+    /// this yields the orderings of the distinct positions of its list.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use permutate::{Permutator, PermutatorWrapper as _};
+    /// #
+    /// let lists = vec![&["ann", "bob"][..], &["ann", "bob", "cid"][..]];
+    /// let output: Vec<_> = Permutator::new(&lists).all_different().collect();
+    /// assert_eq!(output, [["ann", "bob"], ["ann", "cid"], ["bob", "ann"], ["bob", "cid"]]);
+    /// ```
+    ///
+    /// # Panics
+    /// This method will panic if the permutator steps in a shuffled order.
+    pub fn all_different(self) -> Pruned<ListWrap, T, impl FnMut(&[T]) -> bool>
+    where
+        T: PartialEq,
+    {
+        self.with_prune(|prefix: &[T]| match prefix.split_last() {
+            Some((last, rest)) => rest.iter().all(|value| value != last),
+            None => true,
+        })
+    }
+
+    /// Creates an iterator that only generates the permutations in which no two positions
+    /// share the same key, in the same manner as `all_different()`.
+    ///
+    /// # Panics
+    /// This method will panic if the permutator steps in a shuffled order.
+    pub fn all_different_by_key<K, G>(
+        self,
+        mut key: G,
+    ) -> Pruned<ListWrap, T, impl FnMut(&[T]) -> bool>
+    where
+        K: PartialEq,
+        G: FnMut(&T) -> K,
+    {
+        self.with_prune(move |prefix: &[T]| match prefix.split_last() {
+            Some((last, rest)) => {
+                let last = key(last);
+                rest.iter().all(|value| key(value) != last)
+            }
+            None => true,
+        })
+    }
+}
+
+impl<ListWrap, T, F> Pruned<ListWrap, T, F>
+where
+    ListWrap: ListWrapper<Vec<T>>,
+    T: Clone,
+    F: FnMut(&[T]) -> bool,
+{
+    /// Provides similar functionality as the `Iterator` traits `next` method, but allows the
+    /// ability to re-use a prior allocation, in the same manner as
+    /// `Permutator::next_with_buffer()`.
+    ///
+    /// # Panics
+    /// This method will panic if the supplied buffer's length is invalid.
+    pub fn next_with_buffer<'b>(&mut self, buffer: &'b mut Vec<T>) -> Option<&'b mut Vec<T>> {
+        let counters = &mut self.permutator.indexes;
+        loop {
+            if counters.is_exhausted() {
+                return None;
+            }
+
+            ListWrap::next_with_buffer(&self.permutator.lists, counters.indexes.as_ref(), buffer);
+
+            // Only the prefixes that contain a changed index need to be checked again.
+            let checked_indexes = &self.checked_indexes;
+            let unchanged = counters
+                .priority
+                .as_ref()
+                .iter()
+                .take_while(|&&list| {
+                    checked_indexes.get(list) == Some(&counters.indexes.as_ref()[list])
+                })
+                .count();
+            self.prefix.clear();
+            self.prefix.extend(
+                counters
+                    .priority
+                    .as_ref()
+                    .iter()
+                    .map(|&list| buffer[list].clone()),
+            );
+            self.checked_indexes.clear();
+            self.checked_indexes
+                .extend_from_slice(counters.indexes.as_ref());
+
+            let keep = &mut self.keep;
+            let prefix = &self.prefix;
+            let rejected = (self.accepted.min(unchanged) + 1..=prefix.len())
+                .find(|&depth| !keep(&prefix[..depth]));
+
+            match rejected {
+                Some(depth) => {
+                    self.accepted = depth - 1;
+                    counters.skip_block(depth, true);
+                }
+                None => {
+                    self.accepted = prefix.len();
+                    counters.curr_iter += 1;
+                    counters.increment(prefix.len() - 1);
+                    return Some(buffer);
+                }
+            }
+        }
+    }
+}
+
+impl<ListWrap, T, F> Iterator for Pruned<ListWrap, T, F>
+where
+    ListWrap: ListWrapper<Vec<T>>,
+    T: Clone,
+    F: FnMut(&[T]) -> bool,
+{
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.permutator.indexes.is_exhausted() {
+            return None;
+        }
+
+        let mut output = ListWrap::next_item(
+            &self.permutator.lists,
+            self.permutator.indexes.indexes.as_ref(),
+        );
+        self.next_with_buffer(&mut output)?;
+        Some(output)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (0, self.permutator.size_hint().1)
+    }
+}
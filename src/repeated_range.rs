@@ -0,0 +1,136 @@
+use std::convert::TryFrom;
+use std::ops::RangeInclusive;
+
+use {Permutator, PermutatorWrapper, RepeatedLen};
+
+/// Generates every word over a single list for each length within a range, in order of
+/// increasing length. Each length is permutated in the same manner as `RepeatedLen`.
+///
+/// # Example
+///
+/// ```rust
+/// # use permutate::RepeatedRange;
+/// #
+/// let list = &["a", "b"][..];
+/// let words: Vec<String> = RepeatedRange::new(list, 1..=2)
+///     .map(|word| word.concat())
+///     .collect();
+///
+/// assert_eq!(words, ["a", "b", "aa", "ab", "ba", "bb"]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct RepeatedRange<'a, T: 'a + Copy> {
+    /// The permutator of the length that is currently being generated.
+    permutator: Permutator<RepeatedLen<'a, T>, Vec<T>>,
+    /// The list that the words are generated from.
+    list: &'a [T],
+    /// The shortest length of the words.
+    min: usize,
+    /// The longest length of the words.
+    max: usize,
+}
+
+impl<'a, T: Copy> RepeatedRange<'a, T> {
+    /// Initialize a new `RepeatedRange` over the `list`, for each length within `lens`.
+    ///
+    /// # Panics
+    /// This method will panic if the range is empty, if it contains a length of `0`, or if
+    /// the list is empty.
+    pub fn new(list: &'a [T], lens: RangeInclusive<usize>) -> RepeatedRange<'a, T> {
+        let (min, max) = lens.into_inner();
+        assert!(min != 0, "words must contain at least one value");
+        assert!(min <= max, "the range of lengths is empty");
+
+        RepeatedRange {
+            permutator: Permutator::new(&RepeatedLen::new(list, min)),
+            list,
+            min,
+            max,
+        }
+    }
+
+    /// The length of the words that are currently being generated.
+    pub fn current_len(&self) -> usize {
+        self.permutator.lists.len()
+    }
+
+    /// Returns the total number of words possible across every length, or `None` if the
+    /// count does not fit within a `u128`.
+    pub fn checked_max_permutations(&self) -> Option<u128> {
+        let nvalues = self.list.len() as u128;
+        (self.min..=self.max).try_fold(0u128, |count, len| {
+            count.checked_add(nvalues.checked_pow(u32::try_from(len).ok()?)?)
+        })
+    }
+
+    /// Resets the internal state to allow you to start permutating again.
+    pub fn reset(&mut self) {
+        self.permutator = Permutator::new(&RepeatedLen::new(self.list, self.min));
+    }
+
+    /// Provides similar functionality as the `Iterator` traits `next` method, but allows the
+    /// ability to re-use a prior allocation. The buffer is resized whenever the length of the
+    /// words changes.
+    pub fn next_with_buffer<'b>(&mut self, buffer: &'b mut Vec<T>) -> Option<&'b mut Vec<T>> {
+        loop {
+            let len = self.current_len();
+            if buffer.len() != len {
+                buffer.resize(len, self.list[0]);
+            }
+
+            if self.permutator.next_with_buffer(buffer).is_some() {
+                return Some(buffer);
+            }
+
+            if !self.advance() {
+                return None;
+            }
+        }
+    }
+
+    /// The number of words that remain across every length, or `None` if the count does not
+    /// fit within a `u128`.
+    fn remaining(&self) -> Option<u128> {
+        let nvalues = self.list.len() as u128;
+        (self.current_len() + 1..=self.max)
+            .try_fold(self.permutator.indexes.remaining()?, |count, len| {
+                count.checked_add(nvalues.checked_pow(u32::try_from(len).ok()?)?)
+            })
+    }
+
+    /// Moves on towards the next length, returning `false` if every length is exhausted.
+    fn advance(&mut self) -> bool {
+        let len = self.current_len();
+        if len >= self.max {
+            return false;
+        }
+
+        self.permutator = Permutator::new(&RepeatedLen::new(self.list, len + 1));
+        true
+    }
+}
+
+impl<'a, T: Copy> Iterator for RepeatedRange<'a, T> {
+    type Item = Vec<T>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            if let Some(word) = self.permutator.next() {
+                return Some(word);
+            }
+
+            if !self.advance() {
+                return None;
+            }
+        }
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        match self.remaining() {
+            Some(remaining) if remaining <= usize::MAX as u128 => {
+                (remaining as usize, Some(remaining as usize))
+            }
+            _ => (usize::MAX, None),
+        }
+    }
+}
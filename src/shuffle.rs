@@ -0,0 +1,75 @@
+//! A seeded bijection over `0..len`, which is a Feistel network over the smallest even number of
+//! bits that covers `len`, where values that land outside of `len` are walked through the
+//! network again until they land within it.
+
+/// The number of rounds of the Feistel network.
+const ROUNDS: u64 = 6;
+
+/// Mixes the bits of `value`, as in the finalizer of SplitMix64.
+pub fn mix(mut value: u64) -> u64 {
+    value = value.wrapping_add(0x9e37_79b9_7f4a_7c15);
+    value = (value ^ (value >> 30)).wrapping_mul(0xbf58_476d_1ce4_e5b9);
+    value = (value ^ (value >> 27)).wrapping_mul(0x94d0_49bb_1331_11eb);
+    value ^ (value >> 31)
+}
+
+/// The number of bits within each half of the network that covers `0..len`.
+fn half_bits(len: u128) -> u32 {
+    let bits = 128 - (len - 1).leading_zeros();
+    bits.div_ceil(2)
+}
+
+fn round(seed: u64, round: u64, half: u64, mask: u64) -> u64 {
+    mix(mix(seed ^ round.wrapping_mul(0xd6e8_feb8_6659_fd93)) ^ half) & mask
+}
+
+fn encrypt(seed: u64, half_bits: u32, value: u128) -> u128 {
+    let mask = ((1u128 << half_bits) - 1) as u64;
+    let (mut left, mut right) = ((value >> half_bits) as u64, value as u64 & mask);
+    for index in 0..ROUNDS {
+        let next = left ^ round(seed, index, right, mask);
+        left = right;
+        right = next;
+    }
+    (left as u128) << half_bits | right as u128
+}
+
+fn decrypt(seed: u64, half_bits: u32, value: u128) -> u128 {
+    let mask = ((1u128 << half_bits) - 1) as u64;
+    let (mut left, mut right) = ((value >> half_bits) as u64, value as u64 & mask);
+    for index in (0..ROUNDS).rev() {
+        let previous = right ^ round(seed, index, left, mask);
+        right = left;
+        left = previous;
+    }
+    (left as u128) << half_bits | right as u128
+}
+
+/// Maps the position `rank` within `0..len` to its shuffled position.
+pub fn shuffle(seed: u64, len: u128, rank: u128) -> u128 {
+    if len <= 1 {
+        return rank;
+    }
+
+    let half_bits = half_bits(len);
+    let mut value = encrypt(seed, half_bits, rank);
+    while value >= len {
+        value = encrypt(seed, half_bits, value);
+    }
+    value
+}
+
+/// Maps the shuffled position `rank` within `0..len` back to its original position, which
+/// is the inverse of `shuffle()`.
+pub fn unshuffle(seed: u64, len: u128, rank: u128) -> u128 {
+    if len <= 1 {
+        return rank;
+    }
+
+    let half_bits = half_bits(len);
+    let mut value = decrypt(seed, half_bits, rank);
+    while value >= len {
+        value = decrypt(seed, half_bits, value);
+    }
+    value
+}
@@ -0,0 +1,150 @@
+use index_counters::unrank;
+use {ListWrapper, Permutator, ProductOrder};
+
+/// An iterator over every `step`th permutation of a `Permutator`, starting with the next
+/// permutation, which is created with `Permutator::strided()`.
+///
+/// The stride is converted into a mixed-radix delta once, and each step adds that delta to the
+/// indexes with a carry across the lists, rather than generating the permutations in between.
+/// `Iterator::step_by()` may not be specialized on stable Rust, but it is also efficient over a
+/// `Permutator`, as each of its steps is a single call to `nth()`, which jumps to the position.
+#[derive(Clone, Debug)]
+pub struct Strided<ListWrap, ItemWrap>
+where
+    ListWrap: ListWrapper<ItemWrap>,
+{
+    permutator: Permutator<ListWrap, ItemWrap>,
+    /// The number of permutations to advance by after each permutation.
+    step: u128,
+    /// The stride as an index for each list, or `None` if the order cannot be added to.
+    delta: Option<Vec<usize>>,
+}
+
+impl<ListWrap, ItemWrap> Permutator<ListWrap, ItemWrap>
+where
+    ListWrap: ListWrapper<ItemWrap>,
+{
+    /// Creates an iterator that generates the next permutation, and then every `step`th
+    /// permutation after it, at the cost of a single addition across the lists per step.
+    ///
+    /// # Example
+    ///
+    /// ```rust
+    /// # use permutate::{Permutator, PermutatorWrapper as _};
+    /// #
+    /// let lists = vec![&[1, 2, 3][..], &[4, 5, 6][..]];
+    /// let output = Permutator::new(&lists).strided(4).collect::<Vec<_>>();
+    /// assert_eq!(output, [[1, 4], [2, 5], [3, 6]]);
+    /// ```
+    ///
+    /// # Panics
+    /// This method will panic if `step` is `0`.
+    pub fn strided(self, step: u128) -> Strided<ListWrap, ItemWrap> {
+        assert!(step != 0, "the step must not be zero");
+
+        // Only the odometer has carries to follow, so other orders step through the linear position.
+        let delta = match self.indexes.order {
+            ProductOrder::Lexicographic => {
+                let mut delta = vec![0; self.indexes.lens.as_ref().len()];
+                unrank(
+                    self.indexes.lens.as_ref(),
+                    self.indexes.priority.as_ref(),
+                    step,
+                    &mut delta,
+                );
+                Some(delta)
+            }
+            ProductOrder::Gray | ProductOrder::Shuffled(_) => None,
+        };
+
+        Strided {
+            permutator: self,
+            step,
+            delta,
+        }
+    }
+}
+
+impl<ListWrap, ItemWrap> Strided<ListWrap, ItemWrap>
+where
+    ListWrap: ListWrapper<ItemWrap>,
+{
+    /// Provides similar functionality as the `Iterator` traits `next` method, but allows the
+    /// ability to re-use a prior allocation, in the same manner as
+    /// `Permutator::next_with_buffer()`.
+    ///
+    /// # Panics
+    /// This method will panic if the supplied buffer's length is invalid.
+    pub fn next_with_buffer<'b>(&mut self, buffer: &'b mut ItemWrap) -> Option<&'b mut ItemWrap> {
+        if self.permutator.indexes.is_exhausted() {
+            return None;
+        }
+
+        ListWrap::next_with_buffer(
+            &self.permutator.lists,
+            self.permutator.indexes.indexes.as_ref(),
+            buffer,
+        );
+        self.advance();
+        Some(buffer)
+    }
+
+    fn advance(&mut self) {
+        let step = match self.permutator.indexes.remaining() {
+            Some(remaining) => self.step.min(remaining),
+            None => self.step,
+        };
+        self.permutator.indexes.curr_iter = self.permutator.indexes.curr_iter.saturating_add(step);
+        match self.delta {
+            Some(ref delta) => self.permutator.indexes.add_delta(delta),
+            None => self.permutator.indexes.add(self.step),
+        }
+    }
+}
+
+impl<ListWrap, ItemWrap> Iterator for Strided<ListWrap, ItemWrap>
+where
+    ListWrap: ListWrapper<ItemWrap>,
+{
+    type Item = ItemWrap;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.permutator.indexes.is_exhausted() {
+            return None;
+        }
+
+        let output = ListWrap::next_item(
+            &self.permutator.lists,
+            self.permutator.indexes.indexes.as_ref(),
+        );
+        self.advance();
+        Some(output)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let remaining = self
+            .permutator
+            .indexes
+            .remaining()
+            .map(|remaining| remaining / self.step + (remaining % self.step != 0) as u128);
+        match remaining {
+            Some(remaining) if remaining <= usize::MAX as u128 => {
+                (remaining as usize, Some(remaining as usize))
+            }
+            _ => (usize::MAX, None),
+        }
+    }
+}
+
+/// `len()` panics if the number of remaining permutations does not fit within a `usize`.
+impl<ListWrap, ItemWrap> ExactSizeIterator for Strided<ListWrap, ItemWrap>
+where
+    ListWrap: ListWrapper<ItemWrap>,
+{
+    fn len(&self) -> usize {
+        match self.size_hint() {
+            (len, Some(_)) => len,
+            _ => panic!("the number of permutations exceeds a usize"),
+        }
+    }
+}
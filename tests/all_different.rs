@@ -0,0 +1,51 @@
+extern crate permutate;
+use permutate::{KPermutations, Permutator, PermutatorWrapper as _, Repeated, RepeatedLen};
+
+const FIRST: &[&str] = &["ann", "bob", "cid", "dan"];
+const SECOND: &[&str] = &["bob", "cid", "eve"];
+const THIRD: &[&str] = &["ann", "eve", "fay", "cid"];
+
+fn distinct(values: &[&str]) -> bool {
+    values
+        .iter()
+        .enumerate()
+        .all(|(i, value)| !values[..i].contains(value))
+}
+
+#[test]
+fn test_all_different() {
+    let lists = vec![FIRST, SECOND, THIRD];
+    let permutator = Permutator::new(&lists);
+    let expected: Vec<_> = permutator
+        .clone()
+        .filter(|values| distinct(values))
+        .collect();
+    assert_eq!(permutator.all_different().collect::<Vec<_>>(), expected);
+}
+
+#[test]
+// The repeated list must yield the orderings of its distinct positions.
+fn test_repeated() {
+    let list = ["a", "b", "c", "d"];
+    let input: Repeated<_> = [&list[..]];
+    let output: Vec<_> = Permutator::new(&input).all_different().collect();
+    let expected: Vec<_> = KPermutations::new(&RepeatedLen::new(&list, 4)).collect();
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn test_all_different_by_key() {
+    let lists = vec![&["Ann", "Bob"][..], &["ann", "cid", "BOB"][..]];
+    let output: Vec<_> = Permutator::new(&lists)
+        .all_different_by_key(|name| name.to_lowercase())
+        .collect();
+    assert_eq!(
+        output,
+        [
+            ["Ann", "cid"],
+            ["Ann", "BOB"],
+            ["Bob", "ann"],
+            ["Bob", "cid"]
+        ]
+    );
+}
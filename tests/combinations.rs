@@ -0,0 +1,97 @@
+extern crate permutate;
+use permutate::{Combinations, Permutator, PermutatorWrapper as _, RepeatedLen};
+
+const LIST: &[&str] = &["a", "b", "c", "d", "e", "f"];
+
+#[test]
+// The combinations must be the words whose values are in strictly ascending order.
+fn test_combinations() {
+    for k in 1..=6 {
+        let input = RepeatedLen::new(LIST, k);
+        let expected: Vec<Vec<&str>> = Permutator::new(&input)
+            .filter(|word| word.windows(2).all(|pair| pair[0] < pair[1]))
+            .collect();
+        let mut combinations = Combinations::new(&input);
+        assert_eq!(combinations.max_permutations(), expected.len());
+
+        let mut output = Vec::new();
+        let mut buffer = combinations.next().unwrap();
+        output.push(buffer.clone());
+        while let Some(buffer) = combinations.next_with_buffer(&mut buffer) {
+            output.push(buffer.clone());
+        }
+        assert_eq!(output, expected);
+    }
+}
+
+#[test]
+fn test_rank_and_unrank() {
+    let input = RepeatedLen::new(LIST, 3);
+    let combinations = Combinations::new(&input);
+    let mut iterator = Combinations::new(&input);
+    for rank in 0..20 {
+        let (_, indexes) = iterator.get_index();
+        let expected = iterator.next().unwrap();
+        assert_eq!(combinations.rank_of(&indexes), rank);
+        assert_eq!(combinations.nth_combination(rank), Some(expected));
+    }
+    assert_eq!(iterator.next(), None);
+    assert_eq!(combinations.nth_combination(20), None);
+}
+
+#[test]
+fn test_rev_and_nth() {
+    let input = RepeatedLen::new(LIST, 4);
+    let expected: Vec<Vec<&str>> = Combinations::new(&input).collect();
+    let mut reversed: Vec<Vec<&str>> = Combinations::new(&input).rev().collect();
+    reversed.reverse();
+    assert_eq!(reversed, expected);
+
+    let mut combinations = Combinations::new(&input);
+    assert_eq!(combinations.nth(3).as_ref(), Some(&expected[3]));
+    assert_eq!(combinations.nth(6).as_ref(), Some(&expected[10]));
+    assert_eq!(combinations.next_back().as_ref(), Some(&expected[14]));
+    assert_eq!(combinations.len(), 3);
+    assert_eq!(combinations.nth(3), None);
+}
+
+#[test]
+fn test_huge_counts() {
+    let list: Vec<usize> = (0..100).collect();
+    let mut combinations = Combinations::new(&RepeatedLen::new(&list, 50));
+    let count = 100_891_344_545_564_193_334_812_497_256u128;
+    assert_eq!(combinations.checked_max_permutations(), Some(count));
+
+    combinations.seek(count - 1);
+    assert_eq!(combinations.next(), Some((50..100).collect()));
+    assert_eq!(combinations.next(), None);
+    assert_eq!(combinations.rank_of(&(0..50).collect::<Vec<_>>()), 0);
+
+    let list: Vec<usize> = (0..200).collect();
+    let mut combinations = Combinations::new(&RepeatedLen::new(&list, 100));
+    assert_eq!(combinations.checked_max_permutations(), None);
+
+    // Without ranks that fit within a `u128`, `nth()` must step rather than panic.
+    let mut expected: Vec<usize> = (0..99).collect();
+    expected.push(102);
+    assert_eq!(combinations.nth(3), Some(expected));
+    assert_eq!(combinations.get_index().0, 4);
+}
+
+#[test]
+fn test_set_index() {
+    let input = RepeatedLen::new(LIST, 3);
+    let mut combinations = Combinations::new(&input);
+    combinations.set_index(10, vec![1, 2, 3]);
+    assert_eq!(combinations.next(), Some(vec!["b", "c", "d"]));
+    assert_eq!(combinations.get_index(), (11, vec![1, 2, 4]));
+    combinations.reset();
+    assert_eq!(combinations.next(), Some(vec!["a", "b", "c"]));
+}
+
+#[test]
+#[should_panic]
+fn test_set_index_unordered() {
+    let input = RepeatedLen::new(LIST, 3);
+    Combinations::new(&input).set_index(0, vec![2, 1, 3]);
+}
@@ -0,0 +1,30 @@
+//! Fixtures that are shared between the integration tests. Each test crate only uses some of
+//! them, so the rest would otherwise be reported as dead code.
+#![allow(dead_code)]
+
+use permutate::ProductOrder;
+
+/// Small lists of indexes, where each value is equal to its position within its list.
+pub const A: &[usize] = &[0, 1, 2];
+pub const B: &[usize] = &[0, 1];
+pub const C: &[usize] = &[0, 1, 2, 3];
+
+/// The orders that step through the permutations by their linear positions.
+pub const ORDERS: [ProductOrder; 2] = [ProductOrder::Lexicographic, ProductOrder::Gray];
+
+/// Every order, with a shuffle of an arbitrary seed.
+pub const ALL_ORDERS: [ProductOrder; 3] = [
+    ProductOrder::Lexicographic,
+    ProductOrder::Gray,
+    ProductOrder::Shuffled(7),
+];
+
+/// Lists whose lengths are pairwise coprime, so that strides and shuffles do not line up
+/// with the blocks of any list.
+pub fn get_coprime_input() -> [&'static [usize]; 3] {
+    [
+        &[0, 1, 2, 3, 4, 5, 6],
+        &[0, 1, 2],
+        &[0, 1, 2, 3, 4, 5, 6, 7, 8, 9, 10],
+    ]
+}
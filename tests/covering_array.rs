@@ -0,0 +1,105 @@
+extern crate permutate;
+use permutate::{CoveringArray, ListWrapper};
+
+// Ensures that every combination of values from any `strength` lists appears in a row.
+fn assert_covers(lens: &[usize], rows: &[Vec<usize>], strength: usize) {
+    let mut combo: Vec<usize> = (0..strength).collect();
+    loop {
+        let size: usize = combo.iter().map(|&list| lens[list]).product();
+        for mut slot in 0..size {
+            let mut values = vec![0; strength];
+            for (value, &list) in values.iter_mut().zip(&combo).rev() {
+                *value = slot % lens[list];
+                slot /= lens[list];
+            }
+            assert!(
+                rows.iter().any(|row| combo
+                    .iter()
+                    .zip(&values)
+                    .all(|(&list, &value)| row[list] == value)),
+                "lists {:?} with values {:?} are not covered",
+                combo,
+                values
+            );
+        }
+
+        match (0..strength).rposition(|i| combo[i] < lens.len() - strength + i) {
+            Some(i) => {
+                combo[i] += 1;
+                for j in i + 1..strength {
+                    combo[j] = combo[j - 1] + 1;
+                }
+            }
+            None => break,
+        }
+    }
+}
+
+#[test]
+fn test_pairwise() {
+    let lists = vec![&[0u8, 1, 2][..], &[0, 1, 2], &[0, 1, 2], &[0, 1, 2]];
+    let array = CoveringArray::new(&lists);
+    assert!(array.len() <= 15, "{} rows", array.len());
+    assert_covers(&lists.lens(), array.rows(), 2);
+
+    let rows: Vec<Vec<u8>> = array.collect();
+    let lens = lists.lens();
+    let indexes: Vec<Vec<usize>> = rows
+        .iter()
+        .map(|row| row.iter().map(|&value| value as usize).collect())
+        .collect();
+    assert_covers(&lens, &indexes, 2);
+}
+
+#[test]
+fn test_strengths_and_seeds() {
+    let lists = vec![
+        &[1, 2][..],
+        &[1, 2, 3, 4],
+        &[1, 2, 3],
+        &[1],
+        &[1, 2, 3],
+        &[1, 2],
+    ];
+    let lens = lists.lens();
+    for strength in 1..4 {
+        for seed in 0..3 {
+            let array = CoveringArray::with_strength(&lists, strength, seed);
+            assert_covers(&lens, array.rows(), strength);
+
+            // The same seed must always generate the same rows.
+            let again = CoveringArray::with_strength(&lists, strength, seed);
+            assert_eq!(array.rows(), again.rows());
+        }
+    }
+
+    // A strength beyond the number of lists covers the full product.
+    let array = CoveringArray::with_strength(&lists, 10, 0);
+    assert_eq!(array.len(), 144);
+}
+
+#[test]
+fn test_tuples() {
+    let lists = (
+        &["linux", "macos", "windows"][..],
+        &[32u8, 64][..],
+        &[true, false][..],
+    );
+    let mut array = CoveringArray::new(&lists);
+    assert_covers(&lists.lens(), array.rows(), 2);
+    let expected: Vec<_> = array.clone().collect();
+
+    let mut backward: Vec<_> = array.clone().rev().collect();
+    backward.reverse();
+    assert_eq!(backward, expected);
+
+    let mut buffer = expected[0];
+    let mut output = Vec::new();
+    while let Some(row) = array.next_with_buffer(&mut buffer) {
+        output.push(*row);
+    }
+    assert_eq!(output, expected);
+
+    array.reset();
+    assert_eq!(array.len(), expected.len());
+}
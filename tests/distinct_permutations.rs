@@ -0,0 +1,76 @@
+extern crate permutate;
+use permutate::{DistinctPermutations, KPermutations, PermutatorWrapper as _, RepeatedLen};
+
+#[test]
+// The distinct permutations must match the deduplicated output of every permutation.
+fn test_balloon() {
+    let mut letters: Vec<char> = "balloon".chars().collect();
+    letters.sort();
+
+    let mut expected: Vec<Vec<char>> =
+        KPermutations::new(&RepeatedLen::new(&letters, letters.len())).collect();
+    expected.sort();
+    expected.dedup();
+
+    let mut permutations = DistinctPermutations::new(&letters);
+    assert_eq!(permutations.max_permutations(), 1260);
+    assert_eq!(expected.len(), 1260);
+
+    let mut output = Vec::new();
+    let mut buffer = vec![' '; letters.len()];
+    while let Some(buffer) = permutations.next_with_buffer(&mut buffer) {
+        output.push(buffer.clone());
+    }
+    assert_eq!(output, expected);
+}
+
+#[test]
+// Values that are only `PartialEq` are grouped by their first appearance.
+fn test_partial_eq_values() {
+    let list = [0.5, -1.0, 0.5, -1.0];
+    let permutations = DistinctPermutations::new(&list);
+    assert_eq!(permutations.len(), 6);
+    assert_eq!(
+        permutations.collect::<Vec<_>>(),
+        [
+            [0.5, 0.5, -1.0, -1.0],
+            [0.5, -1.0, 0.5, -1.0],
+            [0.5, -1.0, -1.0, 0.5],
+            [-1.0, 0.5, 0.5, -1.0],
+            [-1.0, 0.5, -1.0, 0.5],
+            [-1.0, -1.0, 0.5, 0.5],
+        ]
+    );
+}
+
+#[test]
+fn test_double_ended_and_reset() {
+    let list = [1, 2, 2, 3, 3, 3];
+    let mut permutations = DistinctPermutations::new(&list);
+    let forward: Vec<_> = permutations.clone().collect();
+    let mut backward: Vec<_> = permutations.clone().rev().collect();
+    backward.reverse();
+    assert_eq!(forward, backward);
+
+    let front = permutations.next();
+    let back = permutations.next_back();
+    assert_eq!(permutations.len(), forward.len() - 2);
+    permutations.reset();
+    assert_eq!(permutations.next(), front);
+    assert_eq!(permutations.next_back(), back);
+}
+
+#[test]
+fn test_huge_counts() {
+    let list: Vec<u8> = (0..40).collect();
+    assert_eq!(
+        DistinctPermutations::new(&list).checked_max_permutations(),
+        None
+    );
+
+    let list = [7u8; 200];
+    let mut permutations = DistinctPermutations::new(&list);
+    assert_eq!(permutations.checked_max_permutations(), Some(1));
+    assert_eq!(permutations.next(), Some(list.to_vec()));
+    assert_eq!(permutations.next(), None);
+}
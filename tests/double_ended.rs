@@ -0,0 +1,77 @@
+extern crate permutate;
+use permutate::{Permutator, PermutatorWrapper as _, Repeated};
+
+#[test]
+fn test_rev_vec() {
+    let input = vec![
+        &["1", "2", "3"][..],
+        &["a", "b"][..],
+        &["x", "y", "z", "w"][..],
+    ];
+    let mut expected: Vec<Vec<&str>> = Permutator::new(&input).collect();
+    expected.reverse();
+    assert_eq!(Permutator::new(&input).rev().collect::<Vec<_>>(), expected);
+}
+
+#[test]
+fn test_rev_tuple() {
+    let input = (&["one", "two", "three"][..], &[false, true][..]);
+    let mut expected: Vec<(&str, bool)> = Permutator::new(&input).collect();
+    expected.reverse();
+    assert_eq!(Permutator::new(&input).rev().collect::<Vec<_>>(), expected);
+}
+
+#[test]
+fn test_rev_repeated() {
+    let input = [&["1", "2", "3"][..]];
+    let mut expected: Vec<Vec<&str>> = Permutator::<Repeated<_>, _>::new(&input).collect();
+    expected.reverse();
+    let output: Vec<Vec<&str>> = Permutator::<Repeated<_>, _>::new(&input).rev().collect();
+    assert_eq!(output, expected);
+}
+
+#[test]
+// Alternating between both ends must yield every permutation exactly once.
+fn test_meet_in_the_middle() {
+    let input = vec![&["1", "2", "3"][..], &["a", "b", "c"][..]];
+    let expected: Vec<Vec<&str>> = Permutator::new(&input).collect();
+    let mut permutator = Permutator::new(&input);
+    let (mut front, mut back) = (Vec::new(), Vec::new());
+    while let Some(item) = permutator.next() {
+        front.push(item);
+        match permutator.next_back() {
+            Some(item) => back.push(item),
+            None => break,
+        }
+    }
+    assert_eq!(permutator.next(), None);
+    assert_eq!(permutator.next_back(), None);
+
+    back.reverse();
+    front.extend(back);
+    assert_eq!(front, expected);
+}
+
+#[test]
+fn test_next_back_with_buffer() {
+    let input = vec![&["1", "2"][..], &["a", "b"][..]];
+    let mut permutator = Permutator::new(&input);
+    let mut buffer = permutator.next_back().unwrap();
+    assert_eq!(buffer, ["2", "b"]);
+    let expected = [["2", "a"], ["1", "b"], ["1", "a"]];
+    for expected in expected.iter() {
+        let output = permutator.next_back_with_buffer(&mut buffer).unwrap();
+        assert_eq!(output, expected);
+    }
+    assert_eq!(permutator.next_back_with_buffer(&mut buffer), None);
+
+    permutator.reset();
+    assert_eq!(
+        permutator.next_back_with_buffer(&mut buffer).unwrap(),
+        &["2", "b"]
+    );
+    assert_eq!(
+        permutator.next_with_buffer(&mut buffer).unwrap(),
+        &["1", "a"]
+    );
+}
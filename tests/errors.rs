@@ -0,0 +1,140 @@
+extern crate permutate;
+use permutate::{ByRef, ListWrapper, PermutateError, Permutator, PermutatorWrapper as _, Repeated};
+
+#[test]
+fn test_try_new() {
+    let input = vec![&["1", "2", "3"][..], &["a", "b"][..]];
+    let permutator = Permutator::try_new(&input).unwrap();
+    assert_eq!(permutator.count(), 6);
+
+    let input: Vec<&[&str]> = vec![&["1", "2", "3"][..], &[][..]];
+    assert_eq!(
+        Permutator::try_new(&input).err(),
+        Some(PermutateError::EmptyList(1))
+    );
+
+    let input: Vec<&[&str]> = Vec::new();
+    assert_eq!(
+        Permutator::try_new(&input).err(),
+        Some(PermutateError::NoLists)
+    );
+
+    let input: (&[&str], &[bool]) = (&["1"][..], &[][..]);
+    assert_eq!(
+        Permutator::try_new(&input).err(),
+        Some(PermutateError::EmptyList(1))
+    );
+
+    let list: &[&str] = &[];
+    let input = [list];
+    assert_eq!(
+        Permutator::<Repeated<_>, _>::try_new(&input).err(),
+        Some(PermutateError::NoLists)
+    );
+}
+
+// A wrapper whose `lens()` are missing an entry for its second list.
+#[derive(Clone)]
+struct MismatchedLens;
+
+impl ListWrapper<Vec<usize>> for MismatchedLens {
+    type Indexes = Vec<usize>;
+
+    fn wrapper_len(&self) -> usize {
+        2
+    }
+
+    fn lens(&self) -> Vec<usize> {
+        vec![3]
+    }
+
+    fn next_item(&self, indexes: &[usize]) -> Vec<usize> {
+        indexes.to_vec()
+    }
+
+    fn next_with_buffer(&self, indexes: &[usize], buffer: &mut Vec<usize>) {
+        buffer.copy_from_slice(indexes);
+    }
+}
+
+#[test]
+fn test_try_new_mismatched_lens() {
+    assert_eq!(
+        Permutator::try_new(&MismatchedLens).err(),
+        Some(PermutateError::MismatchedLens(2, 1))
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_new_mismatched_lens() {
+    Permutator::new(&MismatchedLens);
+}
+
+#[test]
+fn test_try_new_count_overflow() {
+    let words = &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"][..];
+    let input = vec![words; 38];
+    assert!(Permutator::try_new(&input).is_ok());
+    assert_eq!(
+        Permutator::try_new(&vec![words; 39]).err(),
+        Some(PermutateError::CountOverflow)
+    );
+}
+
+#[test]
+#[should_panic]
+fn test_new_empty_list() {
+    let input: Vec<&[&str]> = vec![&["1", "2", "3"][..], &[][..]];
+    Permutator::new(&input);
+}
+
+#[test]
+fn test_try_set_index() {
+    let input = vec![&["1", "2", "3"][..], &["a", "b"][..]];
+    let mut permutator = Permutator::new(&input);
+    assert_eq!(
+        permutator.try_set_index(0, vec![0, 0, 0]),
+        Err(PermutateError::InvalidLength(2, 3))
+    );
+    assert_eq!(
+        permutator.try_set_index(0, vec![1, 2]),
+        Err(PermutateError::IndexOutOfBounds(1))
+    );
+    assert_eq!(permutator.try_set_index(3, vec![1, 1]), Ok(()));
+    assert_eq!(permutator.next(), Some(vec!["2", "b"]));
+    assert_eq!(permutator.get_index(), (4, vec![2, 0]));
+}
+
+#[test]
+#[should_panic]
+fn test_set_index_out_of_bounds() {
+    let input = vec![&["1", "2", "3"][..], &["a", "b"][..]];
+    Permutator::new(&input).set_index(0, vec![3, 0]);
+}
+
+#[test]
+#[should_panic]
+// The wrappers may be called directly, so out of bounds indexes must panic rather than read
+// past the end of a list.
+fn test_next_item_out_of_bounds() {
+    let input = vec![&["1", "2", "3"][..], &["a", "b"][..]];
+    input.next_item(&[0, 2]);
+}
+
+#[test]
+#[should_panic]
+fn test_next_with_buffer_out_of_bounds() {
+    let input = ByRef(vec![&["1", "2", "3"][..], &["a", "b"][..]]);
+    let mut buffer = vec![&"1"; 2];
+    input.next_with_buffer(&[3, 0], &mut buffer);
+}
+
+#[test]
+fn test_error_display() {
+    assert_eq!(PermutateError::EmptyList(2).to_string(), "list 2 is empty");
+    assert_eq!(
+        PermutateError::InvalidLength(2, 3).to_string(),
+        "indexes have an invalid length: expected 2, found 3"
+    );
+}
@@ -0,0 +1,67 @@
+extern crate permutate;
+use permutate::{Permutator, PermutatorWrapper as _};
+
+fn get_input<'a>() -> Vec<&'a [&'a str]> {
+    vec![
+        &["1", "2", "3"][..],
+        &["a", "b"][..],
+        &["x", "y", "z", "w"][..],
+    ]
+}
+
+#[test]
+fn test_size_hint() {
+    let input = get_input();
+    let mut permutator = Permutator::new(&input);
+    assert_eq!(permutator.size_hint(), (24, Some(24)));
+    permutator.next();
+    permutator.next_back();
+    assert_eq!(permutator.len(), 22);
+    permutator.nth(10);
+    assert_eq!(permutator.len(), 11);
+    permutator.by_ref().for_each(drop);
+    assert_eq!(permutator.size_hint(), (0, Some(0)));
+}
+
+#[test]
+fn test_count_and_last() {
+    let input = get_input();
+    let expected: Vec<Vec<&str>> = Permutator::new(&input).collect();
+    assert_eq!(expected.len(), 24);
+    assert_eq!(Permutator::new(&input).count(), 24);
+    assert_eq!(Permutator::new(&input).last().as_ref(), expected.last());
+
+    let mut permutator = Permutator::new(&input);
+    permutator.nth(23);
+    assert_eq!(permutator.clone().count(), 0);
+    assert_eq!(permutator.last(), None);
+}
+
+#[test]
+// Huge permutators must answer without enumerating their permutations.
+fn test_count_large() {
+    let digits = &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"][..];
+    let input = vec![digits; 12];
+    assert_eq!(Permutator::new(&input).count(), 1_000_000_000_000);
+    assert_eq!(
+        Permutator::new(&input).last().unwrap().concat(),
+        "999999999999"
+    );
+}
+
+#[test]
+fn test_collect_preallocates() {
+    let input = get_input();
+    let permutator = Permutator::new(&input);
+    assert_eq!(permutator.size_hint(), (24, Some(24)));
+    let output: Vec<Vec<&str>> = permutator.collect();
+    assert_eq!(output.len(), 24);
+}
+
+#[test]
+#[should_panic(expected = "the number of permutations exceeds a usize")]
+fn test_len_exceeds_usize() {
+    let list: &[u8] = &[0; 256];
+    let input = vec![list; 9];
+    Permutator::new(&input).len();
+}
@@ -0,0 +1,78 @@
+extern crate permutate;
+use permutate::{DimensionPriority, Fixed, ListWrapper, Permutator, PermutatorWrapper};
+
+mod common;
+use common::{A, ALL_ORDERS, B, C};
+
+#[test]
+// The arrays must match the vectors that are yielded for the same lists.
+fn test_fixed_matches_vec() {
+    let lists = [A, B, C, B];
+    for &order in &ALL_ORDERS {
+        let expected: Vec<Vec<usize>> = Permutator::with_order(&lists.to_vec(), order).collect();
+        let output: Vec<[usize; 4]> = Permutator::with_order(&Fixed(lists), order).collect();
+        assert_eq!(output.len(), expected.len());
+        assert!(output
+            .iter()
+            .zip(expected.iter())
+            .all(|(o, e)| o[..] == e[..]));
+
+        let reversed: Vec<[usize; 4]> =
+            Permutator::with_order(&Fixed(lists), order).rev().collect();
+        assert!(reversed.iter().rev().eq(output.iter()));
+    }
+}
+
+#[test]
+fn test_fixed_with_buffer() {
+    let lists = Fixed([A, B, C]);
+    let expected: Vec<[usize; 3]> = Permutator::new(&lists).collect();
+    let mut permutator = Permutator::new(&lists);
+    let mut buffer = [0; 3];
+    let mut output = Vec::new();
+    while let Some(values) = permutator.next_with_buffer(&mut buffer) {
+        output.push(*values);
+    }
+    assert_eq!(output, expected);
+
+    permutator.reset();
+    let mut lent = Vec::new();
+    permutator.for_each_ref(|&values| lent.push(values));
+    assert_eq!(lent, expected);
+}
+
+#[test]
+fn test_fixed_lookup() {
+    let lists = Fixed([A, B, C]);
+    let permutator = Permutator::new(&lists);
+    assert_eq!(permutator.nth_permutation(13), Some([1, 1, 1]));
+    assert_eq!(permutator.rank_of_item(&[1, 1, 1]), Some(13));
+    assert_eq!(permutator.rank_of_item(&[1, 2, 1]), None);
+}
+
+// Only compiles if the wrapper keeps the indexes of its three lists in an array.
+fn assert_array_indexes<L, I>(_: &L)
+where
+    L: ListWrapper<I, Indexes = [usize; 3]>,
+{
+}
+
+#[test]
+// The indexes of a fixed number of lists are kept in arrays rather than vectors.
+fn test_fixed_counters() {
+    let lists = Fixed([A, B, C]);
+    assert_array_indexes(&lists);
+
+    let mut permutator = Permutator::new(&lists);
+    permutator.set_priority(DimensionPriority::FirstFastest);
+    assert_eq!(permutator.priority(), &[2, 1, 0]);
+    assert_eq!(permutator.next(), Some([0, 0, 0]));
+    assert_eq!(permutator.next(), Some([1, 0, 0]));
+    assert_eq!(permutator.get_index(), (2, vec![2, 0, 0]));
+
+    permutator.set_index(5, vec![0, 1, 3]);
+    assert_eq!(permutator.next(), Some([0, 1, 3]));
+    permutator.seek(23);
+    assert_eq!(permutator.next(), Some([2, 1, 3]));
+    assert_eq!(permutator.next(), None);
+}
@@ -0,0 +1,100 @@
+extern crate permutate;
+use permutate::{IndexChange, Permutator, PermutatorWrapper as _, ProductOrder};
+
+mod common;
+use common::{A, B, C};
+
+fn changes(previous: &[usize], next: &[usize]) -> Vec<IndexChange> {
+    previous
+        .iter()
+        .zip(next)
+        .enumerate()
+        .filter(|&(_, (from, to))| from != to)
+        .map(|(list, (&from, &to))| IndexChange { list, from, to })
+        .collect()
+}
+
+#[test]
+// Successive permutations must differ by a single step within a single list.
+fn test_single_change() {
+    let inputs = [vec![A, B, C], vec![B, B, B], vec![&A[..1], C], vec![C]];
+    for lists in &inputs {
+        let permutator = Permutator::with_order(lists, ProductOrder::Gray);
+        let max = permutator.max_permutations();
+        let output: Vec<Vec<usize>> = permutator.collect();
+        assert_eq!(output.len(), max);
+        assert_eq!(output[0], vec![0; lists.len()]);
+
+        let mut sorted = output.clone();
+        sorted.sort();
+        assert_eq!(sorted, Permutator::new(lists).collect::<Vec<_>>());
+
+        for pair in output.windows(2) {
+            let change = changes(&pair[0], &pair[1]);
+            assert_eq!(change.len(), 1);
+            assert_eq!((change[0].from as isize - change[0].to as isize).abs(), 1);
+        }
+    }
+}
+
+#[test]
+fn test_random_access() {
+    let lists = vec![A, B, C];
+    let permutator = Permutator::with_order(&lists, ProductOrder::Gray);
+    let output: Vec<Vec<usize>> = permutator.clone().collect();
+    for (rank, permutation) in output.iter().enumerate() {
+        assert_eq!(
+            permutator.nth_permutation(rank as u128).as_ref(),
+            Some(permutation)
+        );
+        assert_eq!(permutator.rank_of(permutation), rank as u128);
+        assert_eq!(permutator.rank_of_item(permutation), Some(rank as u128));
+
+        let mut seeked = permutator.clone();
+        seeked.seek(rank as u128);
+        assert_eq!(seeked.next().as_ref(), Some(permutation));
+        assert_eq!(permutator.clone().nth(rank).as_ref(), Some(permutation));
+    }
+    assert_eq!(permutator.nth_permutation(output.len() as u128), None);
+}
+
+#[test]
+fn test_double_ended_and_reset() {
+    let lists = vec![A, B, C];
+    let mut permutator = Permutator::with_order(&lists, ProductOrder::Gray);
+    let forward: Vec<Vec<usize>> = permutator.clone().collect();
+    let mut backward: Vec<Vec<usize>> = permutator.clone().rev().collect();
+    backward.reverse();
+    assert_eq!(forward, backward);
+
+    permutator.next();
+    permutator.next_back();
+    permutator.reset();
+    assert_eq!(permutator.order(), ProductOrder::Gray);
+    assert_eq!(permutator.collect::<Vec<_>>(), forward);
+}
+
+#[test]
+// Applying each reported change to the indexes must reproduce every permutation.
+fn test_next_change() {
+    let lists = vec![A, B, C];
+    let mut permutator = Permutator::with_order(&lists, ProductOrder::Gray);
+    let expected: Vec<Vec<usize>> = permutator.clone().collect();
+
+    let mut indexes = permutator.get_index().1;
+    let mut output = vec![indexes.clone()];
+    while let Some(change) = permutator.next_change() {
+        assert_eq!(indexes[change.list], change.from);
+        indexes[change.list] = change.to;
+        output.push(indexes.clone());
+    }
+    assert_eq!(output, expected);
+    assert_eq!(permutator.next(), None);
+}
+
+#[test]
+#[should_panic(expected = "next_change() requires the Gray code order")]
+fn test_next_change_lexicographic() {
+    let lists = vec![A, B];
+    Permutator::new(&lists).next_change();
+}
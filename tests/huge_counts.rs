@@ -0,0 +1,65 @@
+extern crate permutate;
+use permutate::{Permutator, PermutatorWrapper as _};
+
+fn get_words<'a>() -> &'a [&'a str] {
+    &[
+        "a", "b", "c", "d", "e", "f", "g", "h", "i", "j", "k", "l", "m", "n", "o", "p", "q", "r",
+        "s", "t", "u", "v", "w", "x", "y", "z", "0", "1", "2", "3",
+    ]
+}
+
+#[test]
+// 30^20 permutations exceed a `u64`, but not a `u128`.
+fn test_checked_max_permutations() {
+    let input = vec![get_words(); 20];
+    let mut permutator = Permutator::new(&input);
+    assert_eq!(permutator.checked_max_permutations(), Some(30u128.pow(20)));
+    assert_eq!(permutator.size_hint(), (usize::MAX, None));
+
+    let rank = 30u128.pow(20) - 1;
+    assert_eq!(
+        permutator.nth_permutation(rank).unwrap().concat(),
+        "3".repeat(20)
+    );
+    permutator.seek(rank);
+    assert_eq!(permutator.get_index().0, rank);
+    assert_eq!(permutator.next().unwrap().concat(), "3".repeat(20));
+    assert_eq!(permutator.next(), None);
+}
+
+#[test]
+#[should_panic]
+fn test_max_permutations_overflow() {
+    let input = vec![get_words(); 20];
+    Permutator::new(&input).max_permutations();
+}
+
+#[test]
+// 30^27 permutations exceed a `u128`, but may still be iterated through.
+fn test_count_exceeds_u128() {
+    let input = vec![get_words(); 27];
+    let mut permutator = Permutator::new(&input);
+    assert_eq!(permutator.checked_max_permutations(), None);
+    assert_eq!(permutator.next().unwrap().concat(), "a".repeat(27));
+    assert_eq!(permutator.nth(29).unwrap()[25..], ["b", "a"]);
+    assert_eq!(permutator.next_back().unwrap().concat(), "3".repeat(27));
+}
+
+#[cfg(feature = "big-rank")]
+#[test]
+fn test_big_rank() {
+    use permutate::BigRank;
+
+    let input = vec![get_words(); 27];
+    let permutator = Permutator::new(&input);
+    let max = permutator.big_max_permutations();
+    assert_eq!(max, BigRank::from(30u32).pow(27));
+
+    let last = &max - 1u32;
+    assert_eq!(permutator.big_rank_of(&[29; 27]), last);
+    assert_eq!(
+        permutator.big_nth_permutation(&last).unwrap().concat(),
+        "3".repeat(27)
+    );
+    assert_eq!(permutator.big_nth_permutation(&max), None);
+}
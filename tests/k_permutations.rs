@@ -0,0 +1,155 @@
+extern crate permutate;
+use permutate::{
+    KPermutations, ListWrapper, PermutationOrder, Permutator, PermutatorWrapper, RepeatedLen,
+};
+
+const LIST: &[&str] = &["a", "b", "c", "d", "e", "f"];
+
+// A consumer that only knows about the `PermutatorWrapper` surface.
+fn collect_with_buffer<P, LW>(permutator: &mut P) -> Vec<Vec<&'static str>>
+where
+    P: PermutatorWrapper<LW, Vec<&'static str>> + Iterator<Item = Vec<&'static str>>,
+    LW: ListWrapper<Vec<&'static str>> + Clone,
+{
+    let mut output = Vec::new();
+    if let Some(mut buffer) = permutator.next() {
+        output.push(buffer.clone());
+        while let Some(buffer) = permutator.next_with_buffer(&mut buffer) {
+            output.push(buffer.clone());
+        }
+    }
+    output
+}
+
+fn is_distinct(word: &[&str]) -> bool {
+    word.iter()
+        .enumerate()
+        .all(|(i, value)| !word[..i].contains(value))
+}
+
+#[test]
+fn test_lexicographic() {
+    for k in 1..=5 {
+        let input = RepeatedLen::new(&LIST[..5], k);
+        let expected: Vec<Vec<&str>> = Permutator::new(&input)
+            .filter(|word| is_distinct(word))
+            .collect();
+        let mut permutator = KPermutations::new(&input);
+        assert_eq!(permutator.max_permutations(), expected.len());
+        assert_eq!(collect_with_buffer(&mut permutator), expected);
+    }
+}
+
+#[test]
+fn test_heap() {
+    let input = RepeatedLen::new(&LIST[..3], 3);
+    let output: Vec<Vec<&str>> =
+        KPermutations::with_order(&input, PermutationOrder::Heap).collect();
+    let expected = [
+        ["a", "b", "c"],
+        ["b", "a", "c"],
+        ["c", "a", "b"],
+        ["a", "c", "b"],
+        ["b", "c", "a"],
+        ["c", "b", "a"],
+    ];
+    assert_eq!(output, expected);
+}
+
+#[test]
+// Heap's order must visit the same permutations, with a single swap within each selection.
+fn test_heap_minimal_change() {
+    for k in 1..=4 {
+        let input = RepeatedLen::new(LIST, k);
+        let mut expected: Vec<Vec<&str>> = KPermutations::new(&input).collect();
+        let output: Vec<Vec<&str>> =
+            KPermutations::with_order(&input, PermutationOrder::Heap).collect();
+
+        for pair in output.windows(2) {
+            let changes = pair[0]
+                .iter()
+                .zip(&pair[1])
+                .filter(|&(a, b)| a != b)
+                .count();
+            let mut sorted = (pair[0].clone(), pair[1].clone());
+            sorted.0.sort();
+            sorted.1.sort();
+            assert!(sorted.0 != sorted.1 || changes == 2 || k == 1);
+        }
+
+        let mut output = output;
+        output.sort();
+        expected.sort();
+        assert_eq!(output, expected);
+    }
+}
+
+#[test]
+fn test_rev() {
+    for &order in &[PermutationOrder::Lexicographic, PermutationOrder::Heap] {
+        for n in 1..=6 {
+            for k in 1..=n {
+                let input = RepeatedLen::new(&LIST[..n], k);
+                let mut expected: Vec<Vec<&str>> =
+                    KPermutations::with_order(&input, order).collect();
+                expected.reverse();
+                let output: Vec<Vec<&str>> =
+                    KPermutations::with_order(&input, order).rev().collect();
+                assert_eq!(output, expected, "{:?} n={} k={}", order, n, k);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_meet_in_the_middle() {
+    let input = RepeatedLen::new(&LIST[..4], 3);
+    for &order in &[PermutationOrder::Lexicographic, PermutationOrder::Heap] {
+        let expected: Vec<Vec<&str>> = KPermutations::with_order(&input, order).collect();
+        let mut permutator = KPermutations::with_order(&input, order);
+        let mut buffer = vec![""; 3];
+        let mut front = Vec::new();
+        let mut back = Vec::new();
+        while let Some(item) = permutator.next() {
+            front.push(item);
+            match permutator.next_back_with_buffer(&mut buffer) {
+                Some(item) => back.push(item.clone()),
+                None => break,
+            }
+        }
+        assert_eq!(permutator.len(), 0);
+        back.reverse();
+        front.extend(back);
+        assert_eq!(front, expected);
+    }
+}
+
+#[test]
+fn test_get_and_set_index() {
+    let input = RepeatedLen::new(&LIST[..5], 3);
+    for &order in &[PermutationOrder::Lexicographic, PermutationOrder::Heap] {
+        let mut permutator = KPermutations::with_order(&input, order);
+        permutator.nth(17);
+        let (iter_no, indexes) = permutator.get_index();
+        assert_eq!(iter_no, 18);
+        let expected: Vec<Vec<&str>> = permutator.collect();
+
+        let mut permutator = KPermutations::with_order(&input, order);
+        permutator.set_index(iter_no, indexes);
+        assert_eq!(permutator.collect::<Vec<_>>(), expected);
+    }
+}
+
+#[test]
+fn test_reset_and_counts() {
+    let input = RepeatedLen::new(LIST, 4);
+    let mut permutator = KPermutations::new(&input);
+    assert_eq!(permutator.checked_max_permutations(), Some(360));
+    assert_eq!(permutator.len(), 360);
+    permutator.nth(100);
+    permutator.reset();
+    assert_eq!(permutator.next(), Some(vec!["a", "b", "c", "d"]));
+
+    let input = RepeatedLen::new(&LIST[..2], 3);
+    assert_eq!(KPermutations::new(&input).next(), None);
+}
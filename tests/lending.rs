@@ -0,0 +1,45 @@
+extern crate permutate;
+use permutate::{Permutator, PermutatorWrapper as _};
+
+mod common;
+use common::{A, ALL_ORDERS, B, C};
+
+#[test]
+// Lent permutations must match those that are returned by value.
+fn test_next_slice() {
+    let lists = vec![A, B, C];
+    for &order in &ALL_ORDERS {
+        let expected: Vec<Vec<usize>> = Permutator::with_order(&lists, order).collect();
+        let mut permutator = Permutator::with_order(&lists, order);
+        let mut output = Vec::new();
+        while let Some(values) = permutator.next_slice() {
+            output.push(values.to_vec());
+        }
+        assert_eq!(output, expected);
+        assert_eq!(permutator.next_slice(), None);
+    }
+}
+
+#[test]
+fn test_next_ref_after_seek() {
+    let lists = vec![A, B, C];
+    let expected: Vec<Vec<usize>> = Permutator::new(&lists).collect();
+    let mut permutator = Permutator::new(&lists);
+    assert_eq!(permutator.next_ref(), Some(&expected[0]));
+    permutator.seek(17);
+    assert_eq!(permutator.next_ref(), Some(&expected[17]));
+    permutator.reset();
+    assert_eq!(permutator.next_ref(), Some(&expected[0]));
+}
+
+#[test]
+fn test_for_each_ref() {
+    let lists = (A, B, C);
+    let expected: Vec<(usize, usize, usize)> = Permutator::new(&lists).collect();
+    let mut permutator = Permutator::new(&lists);
+    permutator.next();
+    let mut output = Vec::new();
+    permutator.for_each_ref(|&values| output.push(values));
+    assert_eq!(output, &expected[1..]);
+    assert_eq!(permutator.next_ref(), None);
+}
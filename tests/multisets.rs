@@ -0,0 +1,73 @@
+extern crate permutate;
+use permutate::{Multisets, Permutator, PermutatorWrapper as _, RepeatedLen};
+
+const DIE: &[u8] = &[1, 2, 3, 4, 5, 6];
+
+#[test]
+// The multisets must be the words whose values are in non-decreasing order.
+fn test_multisets() {
+    for k in 1..=4 {
+        let input = RepeatedLen::new(DIE, k);
+        let expected: Vec<Vec<u8>> = Permutator::new(&input)
+            .filter(|word| word.windows(2).all(|pair| pair[0] <= pair[1]))
+            .collect();
+        let mut multisets = Multisets::new(&input);
+        assert_eq!(multisets.max_permutations(), expected.len());
+
+        let mut output = Vec::new();
+        let mut buffer = vec![0; k];
+        while let Some(buffer) = multisets.next_with_buffer(&mut buffer) {
+            output.push(buffer.clone());
+        }
+        assert_eq!(output, expected);
+    }
+}
+
+#[test]
+fn test_dice() {
+    let multisets = Multisets::new(&RepeatedLen::new(DIE, 3));
+    assert_eq!(multisets.len(), 56);
+    let sevens = multisets
+        .filter(|roll| roll.iter().sum::<u8>() == 7)
+        .collect::<Vec<_>>();
+    assert_eq!(sevens, [[1, 1, 5], [1, 2, 4], [1, 3, 3], [2, 2, 3]]);
+}
+
+#[test]
+fn test_rank_and_unrank() {
+    let input = RepeatedLen::new(DIE, 3);
+    let multisets = Multisets::new(&input);
+    let mut iterator = Multisets::new(&input);
+    for rank in 0..56 {
+        let (_, indexes) = iterator.get_index();
+        let expected = iterator.next().unwrap();
+        assert_eq!(multisets.rank_of(&indexes), rank);
+        assert_eq!(multisets.nth_multiset(rank), Some(expected));
+    }
+    assert_eq!(multisets.nth_multiset(56), None);
+
+    let mut multisets = Multisets::new(&input);
+    multisets.seek(54);
+    assert_eq!(multisets.next(), Some(vec![5, 6, 6]));
+    assert_eq!(multisets.next(), Some(vec![6, 6, 6]));
+    assert_eq!(multisets.next(), None);
+}
+
+#[test]
+fn test_rev() {
+    let input = RepeatedLen::new(DIE, 4);
+    let mut expected: Vec<Vec<u8>> = Multisets::new(&input).collect();
+    expected.reverse();
+    assert_eq!(Multisets::new(&input).rev().collect::<Vec<_>>(), expected);
+
+    let mut multisets = Multisets::new(&input);
+    let mut buffer = vec![0; 4];
+    assert_eq!(
+        multisets.next_back_with_buffer(&mut buffer).unwrap(),
+        &[6, 6, 6, 6]
+    );
+    assert_eq!(
+        multisets.next_back_with_buffer(&mut buffer).unwrap(),
+        &[5, 6, 6, 6]
+    );
+}
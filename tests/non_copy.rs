@@ -0,0 +1,95 @@
+extern crate permutate;
+use permutate::{ByClone, ByRef, Permutator, PermutatorWrapper as _};
+
+#[derive(Clone, Debug, PartialEq)]
+struct Reviewer {
+    name: String,
+}
+
+fn reviewers() -> Vec<Reviewer> {
+    vec!["ann", "bob", "cid"]
+        .into_iter()
+        .map(|name| Reviewer {
+            name: name.to_owned(),
+        })
+        .collect()
+}
+
+fn strings(values: &[&str]) -> Vec<String> {
+    values.iter().map(|&value| value.to_owned()).collect()
+}
+
+#[test]
+// The references must point into the original lists.
+fn test_by_ref_vec() {
+    let reviewers = reviewers();
+    let slots = strings(&["morning", "evening"]);
+    let lists = ByRef(vec![&reviewers[..], &reviewers[..], &reviewers[..2]]);
+    let permutator = Permutator::new(&lists);
+    assert_eq!(permutator.len(), 18);
+
+    let output: Vec<Vec<&Reviewer>> = permutator.clone().collect();
+    assert_eq!(
+        output[5][0] as *const Reviewer,
+        &reviewers[0] as *const Reviewer
+    );
+    assert_eq!(output[5][1].name, "cid");
+    assert_eq!(
+        output[5][2] as *const Reviewer,
+        &reviewers[1] as *const Reviewer
+    );
+    assert_eq!(permutator.rank_of_item(&output[5]), Some(5));
+
+    let lists = ByRef(vec![&slots[..]]);
+    let mut permutator = Permutator::new(&lists);
+    let mut buffer = permutator.next().unwrap();
+    assert_eq!(buffer, [&slots[0]]);
+    assert_eq!(
+        permutator.next_with_buffer(&mut buffer),
+        Some(&mut vec![&slots[1]])
+    );
+    assert_eq!(permutator.next_with_buffer(&mut buffer), None);
+}
+
+#[test]
+fn test_by_ref_tuple() {
+    let reviewers = reviewers();
+    let slots = strings(&["morning", "evening"]);
+    let lists = ByRef((&reviewers[..], &slots[..]));
+    let output: Vec<(&Reviewer, &String)> = Permutator::new(&lists).collect();
+    assert_eq!(output.len(), 6);
+    assert_eq!(output[3], (&reviewers[1], &slots[1]));
+    assert_eq!(
+        Permutator::new(&lists).rank_of_item(&(&reviewers[2], &slots[0])),
+        Some(4)
+    );
+}
+
+#[test]
+fn test_by_clone() {
+    let reviewers = reviewers();
+    let slots = strings(&["morning", "evening"]);
+
+    let lists = ByClone(vec![&slots[..], &slots[..]]);
+    let output: Vec<Vec<String>> = Permutator::new(&lists).collect();
+    assert_eq!(
+        output,
+        [
+            strings(&["morning", "morning"]),
+            strings(&["morning", "evening"]),
+            strings(&["evening", "morning"]),
+            strings(&["evening", "evening"]),
+        ]
+    );
+
+    let lists = ByClone((&reviewers[..], &slots[..]));
+    let mut permutator = Permutator::new(&lists);
+    let mut buffer = permutator.next().unwrap();
+    let mut output = vec![buffer.clone()];
+    while let Some(buffer) = permutator.next_with_buffer(&mut buffer) {
+        output.push(buffer.clone());
+    }
+    assert_eq!(output.len(), 6);
+    assert_eq!(output[5], (reviewers[2].clone(), slots[1].clone()));
+    assert_eq!(permutator.rank_of_item(&output[2]), Some(2));
+}
@@ -0,0 +1,61 @@
+extern crate permutate;
+use permutate::{Optional, Permutator, PermutatorWrapper as _};
+
+#[test]
+fn test_optional_vec() {
+    let input = Optional(vec![&["on", "off"][..], &["x"][..]]);
+    let expected = [
+        [None, None],
+        [None, Some("x")],
+        [Some("on"), None],
+        [Some("on"), Some("x")],
+        [Some("off"), None],
+        [Some("off"), Some("x")],
+    ];
+    let mut permutator = Permutator::new(&input);
+    assert_eq!(permutator.max_permutations(), 6);
+    for (output, expected) in permutator.by_ref().zip(expected[..].iter()) {
+        assert_eq!(&output, expected);
+    }
+
+    permutator.reset();
+    let mut buffer = permutator.next().unwrap();
+    for expected in expected[1..].iter() {
+        assert_eq!(permutator.next_with_buffer(&mut buffer).unwrap(), expected);
+    }
+    assert_eq!(permutator.next_with_buffer(&mut buffer), None);
+}
+
+#[test]
+fn test_optional_tuple() {
+    let input = Optional((&["on", "off"][..], &[true][..], &[][..] as &[i32]));
+    let expected = [
+        (None, None, None),
+        (None, Some(true), None),
+        (Some("on"), None, None),
+        (Some("on"), Some(true), None),
+        (Some("off"), None, None),
+        (Some("off"), Some(true), None),
+    ];
+    let output: Vec<_> = Permutator::new(&input).collect();
+    assert_eq!(output, expected);
+
+    let permutator = Permutator::new(&input);
+    assert_eq!(
+        permutator.rank_of_item(&(Some("on"), Some(true), None)),
+        Some(3)
+    );
+    assert_eq!(
+        permutator.rank_of_item(&(Some("on"), Some(false), None)),
+        None
+    );
+}
+
+#[test]
+// Empty lists are permitted, as they may only ever be absent.
+fn test_optional_empty_lists() {
+    let list: &[bool] = &[];
+    let input = Optional(vec![list, list]);
+    let output: Vec<Vec<Option<bool>>> = Permutator::try_new(&input).unwrap().collect();
+    assert_eq!(output, [[None, None]]);
+}
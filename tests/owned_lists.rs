@@ -0,0 +1,115 @@
+extern crate permutate;
+use permutate::{ByClone, Permutator, PermutatorWrapper as _};
+use std::sync::Arc;
+use std::thread;
+
+// The lists are built here, and the permutator outlives them.
+fn build_permutator() -> Permutator<Vec<Vec<u32>>, Vec<u32>> {
+    let lists: Vec<Vec<u32>> = (1..4).map(|len| (0..len).collect()).collect();
+    Permutator::new(&lists)
+}
+
+// The strings are built here, and cloned out of the lists that the permutator owns.
+fn build_string_permutator() -> Permutator<ByClone<Vec<Vec<String>>>, Vec<String>> {
+    let lists: Vec<Vec<String>> = vec![
+        vec!["one".to_owned(), "two".to_owned()],
+        vec!["three".to_owned(), "four".to_owned(), "five".to_owned()],
+    ];
+    Permutator::new(&ByClone(lists))
+}
+
+fn assert_send_static<T: Send + 'static>(_: &T) {}
+
+#[test]
+fn test_vec_of_vecs() {
+    let permutator = build_permutator();
+    assert_send_static(&permutator);
+    assert_eq!(permutator.len(), 6);
+    assert_eq!(permutator.rank_of_item(&vec![0, 1, 2]), Some(5));
+
+    let borrowed_lists = vec![&[0][..], &[0, 1][..], &[0, 1, 2][..]];
+    let expected: Vec<Vec<u32>> = Permutator::new(&borrowed_lists).collect();
+    assert_eq!(permutator.collect::<Vec<_>>(), expected);
+}
+
+#[test]
+fn test_arc_lists_across_threads() {
+    let shared: Arc<[char]> = Arc::from(&['a', 'b', 'c'][..]);
+    let lists = vec![shared.clone(), Arc::from(&['x', 'y'][..]), shared];
+    let permutator = Permutator::new(&lists);
+    assert_send_static(&permutator);
+
+    let (left, right) = permutator.split_at(9);
+    let handles: Vec<_> = vec![left, right]
+        .into_iter()
+        .map(|mut permutator| {
+            thread::spawn(move || {
+                let mut buffer = vec![' '; 3];
+                let mut output = Vec::new();
+                while let Some(buffer) = permutator.next_with_buffer(&mut buffer) {
+                    output.push(buffer.iter().collect::<String>());
+                }
+                output
+            })
+        })
+        .collect();
+    let output: Vec<String> = handles
+        .into_iter()
+        .flat_map(|handle| handle.join().unwrap())
+        .collect();
+
+    assert_eq!(output.len(), 18);
+    assert_eq!(output[0], "axa");
+    assert_eq!(output[9], "bya");
+    assert_eq!(output[17], "cyc");
+}
+
+#[test]
+fn test_cloned_strings_across_threads() {
+    let permutator = build_string_permutator();
+    assert_send_static(&permutator);
+
+    let output = thread::spawn(move || {
+        permutator
+            .map(|permutation| permutation.join(" "))
+            .collect::<Vec<String>>()
+    })
+    .join()
+    .unwrap();
+    assert_eq!(
+        output,
+        vec![
+            "one three",
+            "one four",
+            "one five",
+            "two three",
+            "two four",
+            "two five"
+        ]
+    );
+}
+
+#[test]
+fn test_cloned_arc_lists() {
+    let lists: Vec<Arc<[String]>> = vec![
+        Arc::from(vec!["a".to_owned(), "b".to_owned()]),
+        Arc::from(vec!["c".to_owned()]),
+    ];
+    let mut permutator = Permutator::new(&ByClone(lists));
+    assert_send_static(&permutator);
+    assert_eq!(
+        permutator.rank_of_item(&vec!["b".to_owned(), "c".to_owned()]),
+        Some(1)
+    );
+
+    let mut buffer = vec![String::new(), String::new()];
+    assert_eq!(
+        permutator.next_with_buffer(&mut buffer).unwrap()[..],
+        ["a", "c"]
+    );
+    assert_eq!(
+        permutator.next_with_buffer(&mut buffer).unwrap()[..],
+        ["b", "c"]
+    );
+    assert_eq!(permutator.next_with_buffer(&mut buffer), None);
+}
@@ -0,0 +1,65 @@
+extern crate permutate;
+use permutate::{Optional, Permutator, PermutatorWrapper as _, PowerSet, PowerSetOrder};
+
+const LIST: &[u8] = &[1, 2, 3, 4, 5];
+
+#[test]
+// The binary counter order must match the subsets selected by the bits of each position.
+fn test_binary_counter() {
+    let mut power_set = PowerSet::new(LIST);
+    assert_eq!(power_set.max_permutations(), 32);
+
+    let mut buffer = Vec::new();
+    let mut position = 0u32;
+    while let Some(subset) = power_set.next_with_buffer(&mut buffer) {
+        let expected: Vec<u8> = (0..LIST.len())
+            .filter(|bit| position & (1 << bit) != 0)
+            .map(|bit| LIST[bit])
+            .collect();
+        assert_eq!(*subset, expected);
+        position += 1;
+    }
+    assert_eq!(position, 32);
+}
+
+#[test]
+// The ascending size order must contain every subset exactly once, ordered by size.
+fn test_ascending_size() {
+    let input = Optional(vec![
+        &LIST[..1],
+        &LIST[1..2],
+        &LIST[2..3],
+        &LIST[3..4],
+        &LIST[4..],
+    ]);
+    let mut expected: Vec<Vec<u8>> = Permutator::new(&input)
+        .map(|subset| subset.into_iter().flatten().collect())
+        .collect();
+    expected.sort_by(|a: &Vec<u8>, b| a.len().cmp(&b.len()).then(a.cmp(b)));
+
+    let power_set = PowerSet::with_order(LIST, PowerSetOrder::AscendingSize);
+    assert_eq!(power_set.len(), 32);
+    assert_eq!(power_set.collect::<Vec<_>>(), expected);
+}
+
+#[test]
+fn test_empty_list_and_reset() {
+    let empty: &[u8] = &[];
+    let mut power_set = PowerSet::new(empty);
+    assert_eq!(power_set.next(), Some(Vec::new()));
+    assert_eq!(power_set.next(), None);
+
+    let mut power_set = PowerSet::with_order(LIST, PowerSetOrder::AscendingSize);
+    let first: Vec<_> = power_set.by_ref().take(10).collect();
+    assert_eq!(power_set.len(), 22);
+    power_set.reset();
+    assert_eq!(power_set.take(10).collect::<Vec<_>>(), first);
+}
+
+#[test]
+fn test_huge_list() {
+    let list = [0u8; 200];
+    let mut power_set = PowerSet::new(&list[..]);
+    assert_eq!(power_set.checked_max_permutations(), None);
+    assert_eq!(power_set.nth(3), Some(vec![0, 0]));
+}
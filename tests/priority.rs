@@ -0,0 +1,102 @@
+extern crate permutate;
+use permutate::{DimensionPriority, Permutator, PermutatorWrapper as _, ProductOrder};
+
+mod common;
+
+fn get_input() -> Vec<&'static [&'static str]> {
+    vec![
+        &["a", "b", "c"][..],
+        &["1", "2"][..],
+        &["x", "y", "z", "w"][..],
+    ]
+}
+
+// The permutations of the lists in the given priority, with the values moved back into the
+// order of their lists.
+fn reordered(priority: &[usize], order: ProductOrder) -> Vec<Vec<&'static str>> {
+    let lists = get_input();
+    let permuted: Vec<&[&str]> = priority.iter().map(|&list| lists[list]).collect();
+    Permutator::with_order(&permuted, order)
+        .map(|values| {
+            let mut output = vec![""; values.len()];
+            for (&list, value) in priority.iter().zip(values) {
+                output[list] = value;
+            }
+            output
+        })
+        .collect()
+}
+
+#[test]
+fn test_priorities() {
+    let lists = get_input();
+    let cases = [
+        (DimensionPriority::LastFastest, [0, 1, 2]),
+        (DimensionPriority::FirstFastest, [2, 1, 0]),
+        (DimensionPriority::Explicit(vec![2, 0, 1]), [2, 0, 1]),
+    ];
+
+    for &order in &common::ORDERS {
+        for (priority, resolved) in &cases {
+            let mut permutator = Permutator::with_order(&lists, order);
+            permutator.set_priority(priority.clone());
+            assert_eq!(permutator.priority(), &resolved[..]);
+
+            let expected = reordered(resolved, order);
+            assert_eq!(permutator.clone().collect::<Vec<_>>(), expected);
+
+            let mut backward = permutator.clone().rev().collect::<Vec<_>>();
+            backward.reverse();
+            assert_eq!(backward, expected);
+
+            for (rank, permutation) in expected.iter().enumerate() {
+                let rank = rank as u128;
+                assert_eq!(permutator.nth_permutation(rank).as_ref(), Some(permutation));
+                assert_eq!(permutator.rank_of_item(permutation), Some(rank));
+            }
+            assert_eq!(permutator.clone().nth(17).as_ref(), expected.get(17));
+        }
+    }
+}
+
+#[test]
+fn test_set_priority_resets() {
+    let lists = get_input();
+    let mut permutator = Permutator::new(&lists);
+    permutator.next();
+    permutator.next_back();
+    permutator.set_priority(DimensionPriority::FirstFastest);
+    assert_eq!(permutator.len(), 24);
+    assert_eq!(permutator.next(), Some(vec!["a", "1", "x"]));
+    assert_eq!(permutator.next(), Some(vec!["b", "1", "x"]));
+}
+
+#[test]
+#[should_panic(expected = "the priority is not a permutation of the list positions")]
+fn test_invalid_priority() {
+    let lists = get_input();
+    Permutator::new(&lists).set_priority(DimensionPriority::Explicit(vec![0, 0, 1]));
+}
+
+#[cfg(feature = "big-rank")]
+#[test]
+fn test_big_rank_priority() {
+    use permutate::BigRank;
+
+    let lists = get_input();
+    let mut permutator = Permutator::with_order(&lists, ProductOrder::Gray);
+    permutator.set_priority(DimensionPriority::Explicit(vec![1, 2, 0]));
+    for (rank, permutation) in permutator.clone().enumerate() {
+        let big = BigRank::from(rank);
+        assert_eq!(
+            permutator.big_nth_permutation(&big),
+            Some(permutation.clone())
+        );
+        let indexes: Vec<usize> = permutation
+            .iter()
+            .zip(&lists)
+            .map(|(value, list)| list.iter().position(|other| other == value).unwrap())
+            .collect();
+        assert_eq!(permutator.big_rank_of(&indexes), big);
+    }
+}
@@ -0,0 +1,119 @@
+extern crate permutate;
+use permutate::{DimensionPriority, Permutator, PermutatorWrapper as _, ProductOrder};
+
+mod common;
+
+const OS: &[&str] = &["linux", "macos", "windows"];
+const ARCH: &[&str] = &["x86", "arm", "riscv"];
+const COMPILER: &[&str] = &["gcc", "clang", "msvc"];
+const LEVEL: &[&str] = &["O0", "O2", "O3"];
+
+fn compatible(prefix: &[&str]) -> bool {
+    match prefix {
+        ["macos", "riscv"] | ["windows", "riscv"] => false,
+        [os, _, "msvc"] => *os == "windows",
+        ["windows", _, "gcc"] => false,
+        [_, "riscv", _, "O3"] => false,
+        _ => true,
+    }
+}
+
+fn accepted(values: &[&str]) -> bool {
+    (1..=values.len()).all(|depth| compatible(&values[..depth]))
+}
+
+#[test]
+// Pruning must yield the same permutations as filtering, while checking fewer of them.
+fn test_prune_matches_filter() {
+    let lists = vec![OS, ARCH, COMPILER, LEVEL];
+    for &order in &common::ORDERS {
+        let permutator = Permutator::with_order(&lists, order);
+        let expected: Vec<_> = permutator
+            .clone()
+            .filter(|values| accepted(values))
+            .collect();
+
+        let mut checks = 0;
+        let output: Vec<_> = permutator
+            .with_prune(|prefix| {
+                checks += 1;
+                compatible(prefix)
+            })
+            .collect();
+        assert_eq!(output, expected);
+        assert!(checks < 81, "{} checks", checks);
+    }
+}
+
+#[test]
+// The prefixes must follow the priority, while the values remain in the order of the lists.
+fn test_prune_with_priority() {
+    let lists = vec![LEVEL, COMPILER, ARCH, OS];
+    let mut permutator = Permutator::new(&lists);
+    permutator.set_priority(DimensionPriority::FirstFastest);
+    let expected: Vec<_> = permutator
+        .clone()
+        .filter(|values| {
+            let reversed: Vec<&str> = values.iter().rev().cloned().collect();
+            accepted(&reversed)
+        })
+        .collect();
+
+    let mut pruned = permutator.with_prune(compatible);
+    let mut buffer = vec![""; 4];
+    let mut output = Vec::new();
+    while let Some(values) = pruned.next_with_buffer(&mut buffer) {
+        output.push(values.clone());
+    }
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn test_skip_subtree() {
+    let lists = vec![OS, ARCH, COMPILER];
+    for &order in &common::ORDERS {
+        let mut permutator = Permutator::with_order(&lists, order);
+        let expected: Vec<_> = permutator
+            .clone()
+            .filter(|values| values[0] != "macos" && values[..2] != ["linux", "arm"])
+            .collect();
+
+        let mut output = Vec::new();
+        while let Some(values) = permutator.next() {
+            if values[0] == "macos" {
+                permutator.skip_subtree(1);
+            } else if values[..2] == ["linux", "arm"] {
+                permutator.skip_subtree(2);
+            } else {
+                output.push(values);
+            }
+        }
+        assert_eq!(output, expected);
+    }
+}
+
+#[test]
+fn test_skip_subtree_bounds() {
+    let lists = vec![OS, ARCH, COMPILER];
+    let mut permutator = Permutator::new(&lists);
+
+    // Nothing has been generated yet, so nothing is skipped.
+    permutator.skip_subtree(1);
+    assert_eq!(permutator.len(), 27);
+
+    // The back of the permutator is never passed.
+    permutator.next();
+    permutator.nth_back(20);
+    permutator.skip_subtree(0);
+    assert_eq!(permutator.len(), 0);
+    assert_eq!(permutator.next(), None);
+}
+
+#[test]
+#[should_panic(expected = "subtrees may not be skipped in a shuffled order")]
+fn test_skip_subtree_shuffled() {
+    let lists = vec![OS, ARCH];
+    let mut permutator = Permutator::with_order(&lists, ProductOrder::Shuffled(3));
+    permutator.next();
+    permutator.skip_subtree(1);
+}
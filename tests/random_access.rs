@@ -0,0 +1,60 @@
+extern crate permutate;
+use permutate::{Permutator, PermutatorWrapper as _, Repeated};
+
+fn get_input<'a>() -> [&'a [&'a str]; 3] {
+    [
+        &["1", "2", "3"][..],
+        &["a", "b"][..],
+        &["x", "y", "z", "w"][..],
+    ]
+}
+
+#[test]
+// Every rank must map to the same permutation that sequential iteration produces.
+fn test_nth_permutation() {
+    let input = get_input().to_vec();
+    let permutator = Permutator::new(&input);
+    for (rank, expected) in Permutator::new(&input).enumerate() {
+        assert_eq!(permutator.nth_permutation(rank as u128), Some(expected));
+    }
+    assert_eq!(permutator.nth_permutation(24), None);
+}
+
+#[test]
+fn test_seek() {
+    let input = get_input().to_vec();
+    let expected: Vec<Vec<&str>> = Permutator::new(&input).collect();
+    let mut permutator = Permutator::new(&input);
+    permutator.seek(17);
+    assert_eq!(permutator.collect::<Vec<_>>(), &expected[17..]);
+
+    let mut permutator = Permutator::new(&input);
+    permutator.seek(24);
+    assert_eq!(permutator.next(), None);
+}
+
+#[test]
+fn test_nth() {
+    let input = [&["1", "2", "3"][..]];
+    let expected: Vec<Vec<&str>> = Permutator::<Repeated<_>, _>::new(&input).collect();
+    let mut permutator = Permutator::<Repeated<_>, _>::new(&input);
+    assert_eq!(permutator.next().as_ref(), Some(&expected[0]));
+    assert_eq!(permutator.nth(4).as_ref(), Some(&expected[5]));
+    assert_eq!(permutator.nth(20).as_ref(), Some(&expected[26]));
+    assert_eq!(permutator.next(), None);
+
+    let mut permutator = Permutator::<Repeated<_>, _>::new(&input);
+    assert_eq!(permutator.nth(27), None);
+    assert_eq!(permutator.next(), None);
+}
+
+#[test]
+// Skipping ten billion permutations must not enumerate them.
+fn test_nth_large_skip() {
+    let digits = &["0", "1", "2", "3", "4", "5", "6", "7", "8", "9"][..];
+    let input = vec![digits; 11];
+    let mut permutator = Permutator::new(&input);
+    let output = permutator.nth(10_000_000_000).unwrap();
+    assert_eq!(output.concat(), "10000000000");
+    assert_eq!(permutator.next().unwrap().concat(), "10000000001");
+}
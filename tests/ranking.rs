@@ -0,0 +1,55 @@
+extern crate permutate;
+use permutate::{Permutator, PermutatorWrapper as _, Repeated};
+
+#[test]
+// The rank of each permutation's indexes must equal its position in the iteration.
+fn test_rank_of() {
+    let input = vec![
+        &["1", "2", "3"][..],
+        &["a", "b"][..],
+        &["x", "y", "z", "w"][..],
+    ];
+    let mut permutator = Permutator::new(&input);
+    let mut rank = 0;
+    loop {
+        let (_, indexes) = permutator.get_index();
+        match permutator.next() {
+            Some(item) => {
+                assert_eq!(permutator.rank_of(&indexes), rank);
+                assert_eq!(permutator.rank_of_item(&item), Some(rank));
+            }
+            None => break,
+        }
+        rank += 1;
+    }
+    assert_eq!(rank, 24);
+}
+
+#[test]
+fn test_rank_of_item_resume() {
+    let input = (&["one", "two", "three"][..], &[false, true][..]);
+    let mut permutator = Permutator::new(&input);
+    let rank = permutator.rank_of_item(&("two", true)).unwrap();
+    assert_eq!(rank, 3);
+    permutator.seek(rank);
+    assert_eq!(permutator.next(), Some(("two", true)));
+    assert_eq!(permutator.next(), Some(("three", false)));
+
+    assert_eq!(permutator.rank_of_item(&("four", true)), None);
+}
+
+#[test]
+fn test_rank_of_item_repeated() {
+    let input = [&["1", "2", "3"][..]];
+    let permutator = Permutator::<Repeated<_>, _>::new(&input);
+    assert_eq!(permutator.rank_of_item(&vec!["1", "1", "1"]), Some(0));
+    assert_eq!(permutator.rank_of_item(&vec!["2", "3", "1"]), Some(15));
+    assert_eq!(permutator.rank_of_item(&vec!["2", "3"]), None);
+}
+
+#[test]
+#[should_panic]
+fn test_rank_of_out_of_bounds() {
+    let input = vec![&["1", "2", "3"][..], &["a", "b"][..]];
+    Permutator::new(&input).rank_of(&[0, 2]);
+}
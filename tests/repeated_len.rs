@@ -0,0 +1,63 @@
+extern crate permutate;
+use permutate::{Permutator, PermutatorWrapper as _, Repeated, RepeatedLen, RepeatedRange};
+
+fn get_charset() -> Vec<char> {
+    ('a'..='z').chain('A'..='Z').chain('0'..='9').collect()
+}
+
+#[test]
+// A length equal to the list's length must match the `Repeated` wrapper.
+fn test_repeated_len_matches_repeated() {
+    let list = &["1", "2", "3"][..];
+    let repeated = [list];
+    let expected: Vec<Vec<&str>> = Permutator::<Repeated<_>, _>::new(&repeated).collect();
+    let output: Vec<Vec<&str>> = Permutator::new(&RepeatedLen::new(list, 3)).collect();
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn test_repeated_len_charset() {
+    let charset = get_charset();
+    let mut permutator = Permutator::new(&RepeatedLen::new(&charset, 4));
+    assert_eq!(permutator.max_permutations(), 62 * 62 * 62 * 62);
+
+    let mut buffer = permutator.next().unwrap();
+    assert_eq!(buffer, ['a', 'a', 'a', 'a']);
+    assert_eq!(
+        permutator.next_with_buffer(&mut buffer).unwrap(),
+        &['a', 'a', 'a', 'b']
+    );
+    assert_eq!(permutator.next_back().unwrap(), ['9', '9', '9', '9']);
+    assert_eq!(permutator.rank_of_item(&vec!['a', 'a', 'b', 'a']), Some(62));
+}
+
+#[test]
+fn test_repeated_range() {
+    let list = &["a", "b", "c"][..];
+    let mut expected: Vec<Vec<&str>> = Vec::new();
+    for len in 2..=4 {
+        expected.extend(Permutator::new(&RepeatedLen::new(list, len)));
+    }
+
+    let range = RepeatedRange::new(list, 2..=4);
+    assert_eq!(range.checked_max_permutations(), Some(9 + 27 + 81));
+    assert_eq!(range.size_hint(), (117, Some(117)));
+    assert_eq!(range.collect::<Vec<_>>(), expected);
+
+    let mut range = RepeatedRange::new(list, 2..=4);
+    let mut buffer = Vec::new();
+    for expected in expected.iter() {
+        assert_eq!(range.next_with_buffer(&mut buffer).unwrap(), expected);
+    }
+    assert_eq!(range.next_with_buffer(&mut buffer), None);
+
+    range.reset();
+    assert_eq!(range.current_len(), 2);
+    assert_eq!(range.next().as_ref(), expected.first());
+}
+
+#[test]
+#[should_panic]
+fn test_repeated_range_zero_len() {
+    RepeatedRange::new(&["a"][..], 0..=2);
+}
@@ -0,0 +1,104 @@
+extern crate permutate;
+use permutate::{Permutator, PermutatorWrapper as _, ProductOrder};
+
+mod common;
+use common::get_coprime_input;
+
+#[test]
+// Every permutation must be visited exactly once, whatever the number of permutations.
+fn test_visits_every_permutation() {
+    let [a, b, c] = get_coprime_input();
+    let inputs = [
+        vec![&a[..1]],
+        vec![&b[..2]],
+        vec![b],
+        vec![a, b, c],
+        vec![c, c, c, b],
+    ];
+    for lists in &inputs {
+        for seed in 0..4 {
+            let permutator = Permutator::with_order(lists, ProductOrder::Shuffled(seed));
+            let mut output: Vec<Vec<usize>> = permutator.collect();
+            output.sort();
+            assert_eq!(output, Permutator::new(lists).collect::<Vec<_>>());
+        }
+    }
+}
+
+#[test]
+fn test_reproducible_from_seed() {
+    let lists = get_coprime_input().to_vec();
+    let first: Vec<_> = Permutator::with_order(&lists, ProductOrder::Shuffled(42)).collect();
+    let again: Vec<_> = Permutator::with_order(&lists, ProductOrder::Shuffled(42)).collect();
+    let other: Vec<_> = Permutator::with_order(&lists, ProductOrder::Shuffled(43)).collect();
+    assert_eq!(first, again);
+    assert_ne!(first, other);
+    assert_ne!(first, Permutator::new(&lists).collect::<Vec<_>>());
+}
+
+#[test]
+// A traversal must be resumable from the state returned by `get_index()`.
+fn test_resumable() {
+    let lists = get_coprime_input().to_vec();
+    let mut permutator = Permutator::with_order(&lists, ProductOrder::Shuffled(7));
+    let expected: Vec<Vec<usize>> = permutator.clone().collect();
+
+    let mut buffer = vec![0; 3];
+    let mut output = Vec::new();
+    for _ in 0..100 {
+        output.push(permutator.next_with_buffer(&mut buffer).unwrap().clone());
+    }
+    let (iter_no, indexes) = permutator.get_index();
+
+    let mut resumed = Permutator::with_order(&lists, ProductOrder::Shuffled(7));
+    resumed.set_index(iter_no, indexes);
+    let mut seeked = Permutator::with_order(&lists, ProductOrder::Shuffled(7));
+    seeked.seek(iter_no);
+    assert_eq!(seeked.clone().collect::<Vec<_>>(), &expected[100..]);
+
+    output.extend(resumed);
+    assert_eq!(output, expected);
+}
+
+#[test]
+fn test_random_access() {
+    let lists = get_coprime_input().to_vec();
+    let mut permutator = Permutator::with_order(&lists, ProductOrder::Shuffled(1));
+    let expected: Vec<Vec<usize>> = permutator.clone().collect();
+    for (rank, permutation) in expected.iter().enumerate() {
+        assert_eq!(
+            permutator.nth_permutation(rank as u128).as_ref(),
+            Some(permutation)
+        );
+        assert_eq!(permutator.rank_of_item(permutation), Some(rank as u128));
+    }
+
+    let mut backward: Vec<_> = permutator.clone().rev().collect();
+    backward.reverse();
+    assert_eq!(backward, expected);
+
+    assert_eq!(permutator.nth(30).as_ref(), Some(&expected[30]));
+    assert_eq!(permutator.next_back().as_ref(), expected.last());
+    permutator.reset();
+    assert_eq!(permutator.collect::<Vec<_>>(), expected);
+}
+
+#[test]
+#[should_panic(expected = "the number of permutations exceeds a u128")]
+fn test_unbounded() {
+    let lists = vec![get_coprime_input()[2]; 40];
+    Permutator::with_order(&lists, ProductOrder::Shuffled(0));
+}
+
+#[test]
+fn test_huge_keyspace() {
+    let lists = vec![&get_coprime_input()[1][..2]; 127];
+    let mut permutator = Permutator::with_order(&lists, ProductOrder::Shuffled(9));
+    assert_eq!(permutator.checked_max_permutations(), Some(1 << 127));
+    for rank in 0..3 {
+        let permutation = permutator.next().unwrap();
+        assert_eq!(permutator.rank_of_item(&permutation), Some(rank));
+    }
+    let last = permutator.next_back().unwrap();
+    assert_eq!(permutator.rank_of_item(&last), Some((1 << 127) - 1));
+}
@@ -0,0 +1,124 @@
+extern crate permutate;
+#[cfg(feature = "rayon")]
+extern crate rayon;
+use permutate::{DimensionPriority, Permutator, PermutatorWrapper as _, ProductOrder};
+
+mod common;
+
+const A: &[u16] = &[0, 1, 2, 3];
+const B: &[u16] = &[0, 1, 2];
+const C: &[u16] = &[0, 1, 2, 3, 4];
+
+#[test]
+fn test_range() {
+    let lists = vec![A, B, C];
+    for &order in &common::ORDERS {
+        let mut permutator = Permutator::with_order(&lists, order);
+        permutator.set_priority(DimensionPriority::FirstFastest);
+        let expected: Vec<Vec<u16>> = permutator.clone().collect();
+
+        for &(start, end) in &[(0, 60), (0, 0), (7, 31), (59, 60), (60, 60)] {
+            let range = permutator.range(start..end);
+            assert_eq!(range.len(), (end - start) as usize);
+            let forward = range.clone().collect::<Vec<_>>();
+            assert_eq!(&forward[..], &expected[start as usize..end as usize]);
+            let mut backward = range.rev().collect::<Vec<_>>();
+            backward.reverse();
+            assert_eq!(backward, forward);
+        }
+    }
+}
+
+#[test]
+// Splitting must cover the remaining permutations with disjoint halves.
+fn test_split_at() {
+    let lists = vec![A, B, C];
+    let mut permutator = Permutator::new(&lists);
+    let expected: Vec<Vec<u16>> = permutator.clone().collect();
+    permutator.nth(4);
+    permutator.nth_back(9);
+
+    let (left, right) = permutator.split_at(20);
+    assert_eq!(left.collect::<Vec<_>>(), &expected[5..20]);
+    assert_eq!(right.collect::<Vec<_>>(), &expected[20..50]);
+
+    let (left, right) = permutator.split_at(0);
+    assert_eq!(left.len(), 0);
+    assert_eq!(right.collect::<Vec<_>>(), &expected[5..50]);
+
+    let (left, right) = permutator.split_at(100);
+    assert_eq!(left.collect::<Vec<_>>(), &expected[5..50]);
+    assert_eq!(right.len(), 0);
+}
+
+#[test]
+// After `set_index()`, the remaining permutations start from the supplied indexes and wrap
+// around past the last permutation, rather than starting from the iteration number.
+fn test_split_at_set_index() {
+    let lists = vec![&A[..3], &B[..2]];
+    let mut permutator = Permutator::new(&lists);
+    permutator.set_index(0, vec![1, 1]);
+    let expected: Vec<Vec<u16>> = permutator.clone().collect();
+    assert_eq!(expected, [[1, 1], [2, 0], [2, 1], [0, 0], [0, 1], [1, 0]]);
+
+    let (left, right) = permutator.split_at(1);
+    assert_eq!(left.collect::<Vec<_>>(), &expected[..4]);
+    assert_eq!(right.collect::<Vec<_>>(), &expected[4..]);
+
+    let (left, right) = permutator.split_at(3);
+    assert_eq!(left.len(), 0);
+    assert_eq!(right.collect::<Vec<_>>(), expected);
+}
+
+#[test]
+#[should_panic(expected = "the range is out of bounds")]
+fn test_range_out_of_bounds() {
+    let lists = vec![A, B];
+    Permutator::new(&lists).range(3..13);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_parallel_order() {
+    use rayon::prelude::*;
+
+    let lists = vec![A, B, C, A, B, C, A];
+    let permutator = Permutator::with_order(&lists, ProductOrder::Gray);
+    let expected: Vec<u32> = permutator
+        .clone()
+        .map(|values| values.iter().map(|&value| value as u32).sum())
+        .collect();
+    let output: Vec<u32> = permutator
+        .into_par_iter()
+        .map(|values| values.iter().map(|&value| value as u32).sum())
+        .collect();
+    assert_eq!(output, expected);
+
+    let mut permutator = Permutator::new(&lists);
+    permutator.nth(1000);
+    assert_eq!(permutator.clone().into_par_iter().count(), permutator.len());
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+fn test_parallel_set_index() {
+    use rayon::prelude::*;
+
+    let lists = vec![&A[..3], &B[..2]];
+    let mut permutator = Permutator::new(&lists);
+    permutator.set_index(0, vec![1, 1]);
+    let expected: Vec<Vec<u16>> = permutator.clone().collect();
+    let output: Vec<Vec<u16>> = permutator.into_par_iter().with_max_len(1).collect();
+    assert_eq!(output, expected);
+}
+
+#[cfg(feature = "rayon")]
+#[test]
+#[should_panic(expected = "the number of permutations exceeds a usize")]
+fn test_parallel_len_exceeds_usize() {
+    use rayon::prelude::*;
+
+    let list: &[u8] = &[0; 256];
+    let lists = vec![list; 9];
+    Permutator::new(&lists).into_par_iter().len();
+}
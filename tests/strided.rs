@@ -0,0 +1,81 @@
+extern crate permutate;
+use permutate::{DimensionPriority, Permutator, PermutatorWrapper as _};
+
+mod common;
+use common::{get_coprime_input, ORDERS};
+
+#[test]
+// The strided iterator must match the standard `step_by()` adaptor.
+fn test_strides() {
+    let [a, b, c] = get_coprime_input();
+    let lists = vec![a, b, c, b];
+    for &order in &ORDERS {
+        for priority in &[
+            DimensionPriority::LastFastest,
+            DimensionPriority::FirstFastest,
+        ] {
+            let mut permutator = Permutator::with_order(&lists, order);
+            permutator.set_priority(priority.clone());
+            for &step in &[1, 2, 7, 33, 692, 693, 694, 1000] {
+                let expected: Vec<Vec<usize>> = permutator.clone().step_by(step).collect();
+                let strided = permutator.clone().strided(step as u128);
+                assert_eq!(strided.len(), expected.len());
+                assert_eq!(strided.collect::<Vec<_>>(), expected);
+            }
+        }
+    }
+}
+
+#[test]
+fn test_strided_with_buffer() {
+    let lists = get_coprime_input().to_vec();
+    let mut permutator = Permutator::new(&lists);
+    permutator.nth(10);
+    let expected: Vec<Vec<usize>> = permutator.clone().step_by(20).collect();
+
+    let mut strided = permutator.strided(20);
+    let mut buffer = vec![0; 3];
+    let mut output = Vec::new();
+    while let Some(buffer) = strided.next_with_buffer(&mut buffer) {
+        output.push(buffer.clone());
+    }
+    assert_eq!(output, expected);
+}
+
+#[test]
+// After `set_index()`, the strided iterator must start from the supplied indexes and wrap
+// around past the last permutation, in the same manner as `next()`.
+fn test_strided_set_index() {
+    let lists = get_coprime_input().to_vec();
+    for &order in &ORDERS {
+        let mut permutator = Permutator::with_order(&lists, order);
+        permutator.set_index(0, vec![4, 1, 9]);
+        let expected: Vec<Vec<usize>> = permutator.clone().collect();
+        assert_eq!(expected.len(), 231);
+        assert_eq!(expected[0], [4, 1, 9]);
+        assert_eq!(permutator.strided(1).collect::<Vec<_>>(), expected);
+    }
+}
+
+#[test]
+// Huge strides over an unbounded keyspace must not overflow.
+fn test_huge_strides() {
+    let [a, b, c] = get_coprime_input();
+    let lists = vec![c; 40];
+    let mut strided = Permutator::new(&lists).strided(u128::MAX);
+    assert_eq!(strided.next(), Some(vec![0; 40]));
+    assert!(strided.next().is_some());
+
+    let lists = vec![a, b];
+    let mut strided = Permutator::new(&lists).strided(u128::MAX);
+    assert_eq!(strided.len(), 1);
+    assert_eq!(strided.next(), Some(vec![0, 0]));
+    assert_eq!(strided.next(), None);
+}
+
+#[test]
+#[should_panic(expected = "the step must not be zero")]
+fn test_zero_step() {
+    let lists = get_coprime_input().to_vec();
+    Permutator::new(&lists).strided(0);
+}